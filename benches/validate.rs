@@ -0,0 +1,179 @@
+// Compares `validate` with the implementation it replaced, which compiled a format
+// regex and parsed a big integer on every call. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SAMPLES: [&str; 8] = [
+    "GB82WEST12345698765432",
+    "DE89370400440532013000",
+    "FR1420041010050500013M02606",
+    "IT60X0542811101000000123456",
+    "NL91ABNA0417164300",
+    "ES9121000418450200051332",
+    "BE68539007547034",
+    "MU17BOMM0101101030300200000MUR",
+];
+
+const ITERATIONS: u32 = 20_000;
+
+// The old `validate`, copied with only the countries of the samples.
+mod baseline {
+    use regex::Regex;
+
+    #[allow(clippy::all)]
+    mod u256 {
+        uint::construct_uint! {
+            pub(crate) struct U256(4);
+        }
+    }
+
+    #[derive(Debug)]
+    pub enum ValidationError {
+        TooLong,
+        InvalidChar,
+        InvalidCountryCode,
+        InvalidLength,
+        InvalidFormat,
+        CountryCheckFailed,
+        InvalidIban,
+    }
+
+    // The length and the format of `Country::length` and `Country::format`.
+    fn country(code: &str) -> Option<(usize, String)> {
+        let n = |num: usize| format!("[0-9]{{{}}}", num);
+        let c = |num: usize| format!("[a-zA-Z0-9]{{{}}}", num);
+        let a = |num: usize| format!("[A-Z]{{{}}}", num);
+        let country = match code {
+            "BE" => (16, n(12)),
+            "DE" => (22, n(18)),
+            "ES" => (24, n(20)),
+            "FR" => (27, n(10) + &c(11) + &n(2)),
+            "GB" => (22, a(4) + &n(14)),
+            "IT" => (27, a(1) + &n(10) + &c(12)),
+            "MU" => (30, a(4) + &n(19) + &a(3)),
+            "NL" => (18, a(4) + &n(10)),
+            _ => return None,
+        };
+        Some(country)
+    }
+
+    // `Country::custom_validation`, only Belgium of the samples has a check.
+    fn custom_validation(code: &str, input: &str) -> bool {
+        match code {
+            "BE" => {
+                let end = input.len() - 1;
+                let check_digits: u128 = input[end - 1..=end].parse().unwrap();
+                let check_number: u128 = input[4..=13].parse().unwrap();
+                check_number % 97 == check_digits
+            }
+            _ => true,
+        }
+    }
+
+    pub fn validate<I: AsRef<str>>(input: I) -> Result<String, ValidationError> {
+        let input = input.as_ref();
+
+        // Remove the whitespace.
+        let input: String = input.split_whitespace().collect();
+
+        // IBAN can be at most 34 characters(bytes) long.
+        if input.len() > 34 {
+            return Err(ValidationError::TooLong);
+        };
+
+        // All of the characters must be alphanumeric.
+        if !input.chars().all(|ch| ch.is_alphanumeric()) {
+            return Err(ValidationError::InvalidChar);
+        };
+
+        // IBAN must have at least 2 characters to match a country code.
+        if input.len() < 2 {
+            return Err(ValidationError::InvalidCountryCode);
+        };
+
+        // See if it is a valid Country
+        let country_code = &input[0..2];
+        let (length, format) = match country(country_code) {
+            Some(c) => c,
+            None => return Err(ValidationError::InvalidCountryCode),
+        };
+
+        // Since it is a valid country, check if it is the proper length.
+        if input.len() != length {
+            return Err(ValidationError::InvalidLength);
+        }
+
+        // Also check if the format matches
+        if !Regex::new(&format).unwrap().is_match(&input) {
+            return Err(ValidationError::InvalidFormat);
+        }
+
+        // Do country-specifich checks.
+        if !custom_validation(country_code, &input) {
+            return Err(ValidationError::CountryCheckFailed);
+        }
+
+        // Put the country code to the end of the string.
+        let (start, rest) = input.split_at(4);
+        let mut rearranged = String::with_capacity(34);
+        rearranged.push_str(rest);
+        rearranged.push_str(start);
+
+        // Convert ASCII letters to their code, don't modify numbers.
+        let mut integer_string = String::with_capacity(34);
+        for ch in rearranged.chars() {
+            if ch.is_numeric() {
+                integer_string.push(ch);
+            } else {
+                // This will not panic as we are guaranteed A-Z, a-z
+                let x = ch.to_digit(36).unwrap().to_string();
+                integer_string.push_str(&x)
+            }
+        }
+
+        // This will not panic as u256 can hold any IBAN.
+        let integer = u256::U256::from_dec_str(&integer_string).unwrap();
+
+        // Make sure that the remainder is one.
+        if integer % 97 != 1.into() {
+            return Err(ValidationError::InvalidIban);
+        }
+
+        Ok(input)
+    }
+}
+
+fn bench<F: FnMut(&'static str)>(name: &str, mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for &iban in SAMPLES.iter() {
+            f(black_box(iban));
+        }
+    }
+    let elapsed = start.elapsed();
+
+    let calls = ITERATIONS * SAMPLES.len() as u32;
+    let per_call = elapsed / calls;
+    let per_second = f64::from(calls) / elapsed.as_secs_f64();
+    println!(
+        "{:<20} {:>10?}/call {:>14.0} calls/s",
+        name, per_call, per_second
+    );
+    elapsed
+}
+
+fn main() {
+    let new = bench("validate", |iban| {
+        assert!(schwifty::validate(iban).is_ok());
+    });
+
+    let old = bench("baseline validate", |iban| {
+        assert!(baseline::validate(iban).is_ok());
+    });
+
+    println!(
+        "validate is {:.1}x faster than the baseline",
+        old.as_secs_f64() / new.as_secs_f64()
+    );
+}
@@ -0,0 +1,557 @@
+//! The layout of the Basic Bank Account Number (BBAN) of each country.
+
+use crate::country::Country;
+use core::fmt::{self, Display};
+
+/// The different parts a BBAN is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Component {
+    /// Identifies the bank.
+    BankCode,
+    /// Identifies the branch of the bank, like a sort code.
+    BranchCode,
+    /// The account number within the bank.
+    AccountNumber,
+    /// Check digits of a national checksum.
+    NationalCheckDigits,
+    /// The kind of account.
+    AccountType,
+    /// Identifies the account holder.
+    AccountHolder,
+    /// Currency code of the account.
+    Currency,
+    /// Reserved, always zero.
+    Reserved,
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Component::*;
+        let name = match self {
+            BankCode => "bank code",
+            BranchCode => "branch code",
+            AccountNumber => "account number",
+            NationalCheckDigits => "national check digits",
+            AccountType => "account type",
+            AccountHolder => "account holder",
+            Currency => "currency",
+            Reserved => "reserved part",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The characters allowed in a part of the BBAN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum CharClass {
+    /// Digits, `n` in the IBAN registry.
+    Numeric,
+    /// Uppercase letters, `a` in the IBAN registry.
+    Alpha,
+    /// Digits and letters, `c` in the IBAN registry.
+    Alphanumeric,
+}
+
+impl CharClass {
+    /// Checks if the character belongs to this class.
+    pub fn matches(&self, ch: char) -> bool {
+        match self {
+            CharClass::Numeric => ch.is_ascii_digit(),
+            CharClass::Alpha => ch.is_ascii_uppercase(),
+            CharClass::Alphanumeric => ch.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+// The letter used by the IBAN registry.
+impl Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self {
+            CharClass::Numeric => "n",
+            CharClass::Alpha => "a",
+            CharClass::Alphanumeric => "c",
+        };
+        write!(f, "{}", letter)
+    }
+}
+
+/// A fixed-length run of characters of the same class and component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub struct Segment {
+    /// The part of the BBAN this segment belongs to.
+    pub component: Component,
+    /// The characters allowed in this segment.
+    pub class: CharClass,
+    /// Position of the first character in the BBAN, the IBAN has 4 more characters before it.
+    pub offset: usize,
+    /// Number of characters in this segment.
+    pub length: usize,
+}
+
+/// The layout of the BBAN of a country, returned by [`Country::bban_spec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BbanSpec {
+    country: Country,
+    segments: &'static [Segment],
+}
+
+impl BbanSpec {
+    /// The country of this BBAN.
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    /// Every segment of the BBAN, in order.
+    pub fn segments(&self) -> &'static [Segment] {
+        self.segments
+    }
+
+    /// Length of the BBAN, the IBAN is 4 characters longer.
+    pub fn length(&self) -> usize {
+        self.segments.iter().map(|s| s.length).sum()
+    }
+
+    /// The segments of the given component, none if the BBAN doesn't have it.
+    pub fn component(&self, component: Component) -> impl Iterator<Item = &'static Segment> {
+        self.segments
+            .iter()
+            .filter(move |s| s.component == component)
+    }
+
+    /// Total length of the given component, 0 if the BBAN doesn't have it.
+    pub fn component_length(&self, component: Component) -> usize {
+        self.component(component).map(|s| s.length).sum()
+    }
+}
+
+// National check digits are at most two characters, so they fit on the stack. The two
+// Hungarian ones are put together, the CIN of Italy and San Marino is a letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CheckDigits {
+    bytes: [u8; 2],
+    length: usize,
+}
+
+impl CheckDigits {
+    // Writes the number as this many digits, padded with leading zeros.
+    pub(crate) fn number(value: u32, length: usize) -> CheckDigits {
+        let mut bytes = [0; 2];
+        let mut value = value;
+        for byte in bytes[..length].iter_mut().rev() {
+            *byte = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        CheckDigits { bytes, length }
+    }
+
+    // A single letter.
+    pub(crate) fn letter(letter: u8) -> CheckDigits {
+        CheckDigits {
+            bytes: [letter, 0],
+            length: 1,
+        }
+    }
+
+    // None if there are none, or more than fit.
+    fn collect<I: Iterator<Item = u8>>(bytes: I) -> Option<CheckDigits> {
+        let mut check_digits = CheckDigits {
+            bytes: [0; 2],
+            length: 0,
+        };
+        for byte in bytes {
+            *check_digits.bytes.get_mut(check_digits.length)? = byte;
+            check_digits.length += 1;
+        }
+        if check_digits.length == 0 {
+            return None;
+        }
+        Some(check_digits)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+}
+
+/// Which national check was done on a BBAN, returned by [`Country::verify_national_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NationalCheck {
+    /// The BBAN has no national check digits, and no other national check is known.
+    NotApplicable,
+    /// The national check digits, or the other national check, were verified.
+    Verified,
+    /// The BBAN has national check digits, but this crate doesn't know how to compute them.
+    NotVerifiable,
+    /// The national check failed.
+    Failed,
+}
+
+// Letters used by the IBAN registry: n for digits, a for uppercase letters, c for both.
+macro_rules! class {
+    (n) => {
+        CharClass::Numeric
+    };
+    (a) => {
+        CharClass::Alpha
+    };
+    (c) => {
+        CharClass::Alphanumeric
+    };
+}
+
+// Each segment starts where the one before it ends.
+macro_rules! bban {
+    ($($class:ident($length:literal, $component:ident)),+) => {
+        bban!(@segments 0; []; $($class($length, $component)),+)
+    };
+    (@segments $offset:expr; [$($done:expr),*];
+        $class:ident($length:literal, $component:ident) $(, $rest:ident($rest_length:literal, $rest_component:ident))*) => {
+        bban!(@segments $offset + $length; [$($done,)* Segment {
+            component: Component::$component,
+            class: class!($class),
+            offset: $offset,
+            length: $length,
+        }]; $($rest($rest_length, $rest_component)),*)
+    };
+    (@segments $offset:expr; [$($done:expr),*];) => {
+        &[$($done),*]
+    };
+}
+
+impl Country {
+    // Based on the SWIFT IBAN registry, character classes follow
+    // https://en.wikipedia.org/wiki/International_Bank_Account_Number#IBAN_formats_by_country
+    pub(crate) fn bban(&self) -> &'static [Segment] {
+        use Country::*;
+        match self {
+            Albania => bban![
+                n(3, BankCode),
+                n(4, BranchCode),
+                n(1, NationalCheckDigits),
+                c(16, AccountNumber)
+            ],
+            Andorra => bban![n(4, BankCode), n(4, BranchCode), c(12, AccountNumber)],
+            Austria => bban![n(5, BankCode), n(11, AccountNumber)],
+            Azerbaijan => bban![c(4, BankCode), n(20, AccountNumber)],
+            Bahrain => bban![a(4, BankCode), c(14, AccountNumber)],
+            Belarus => bban![c(4, BankCode), n(4, AccountType), c(16, AccountNumber)],
+            Belgium => bban![
+                n(3, BankCode),
+                n(7, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            BosniaHerzegovina => bban![
+                n(3, BankCode),
+                n(3, BranchCode),
+                n(8, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Brazil => bban![
+                n(8, BankCode),
+                n(5, BranchCode),
+                n(10, AccountNumber),
+                a(1, AccountType),
+                c(1, AccountHolder)
+            ],
+            Bulgaria => bban![
+                a(4, BankCode),
+                n(4, BranchCode),
+                n(2, AccountType),
+                c(8, AccountNumber)
+            ],
+            CostaRica => bban![n(1, Reserved), n(3, BankCode), n(14, AccountNumber)],
+            Croatia => bban![n(7, BankCode), n(10, AccountNumber)],
+            Cyprus => bban![n(3, BankCode), n(5, BranchCode), c(16, AccountNumber)],
+            CzechRepublic => bban![n(4, BankCode), n(16, AccountNumber)],
+            Denmark => bban![n(4, BankCode), n(10, AccountNumber)],
+            DominicanRepublic => bban![a(4, BankCode), n(20, AccountNumber)],
+            EastTimor => bban![
+                n(3, BankCode),
+                n(14, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Egypt => bban![n(4, BankCode), n(4, BranchCode), n(17, AccountNumber)],
+            ElSalvador => bban![a(4, BankCode), n(20, AccountNumber)],
+            Estonia => bban![
+                n(2, BankCode),
+                n(2, BranchCode),
+                n(11, AccountNumber),
+                n(1, NationalCheckDigits)
+            ],
+            FaroeIslands => bban![
+                n(4, BankCode),
+                n(9, AccountNumber),
+                n(1, NationalCheckDigits)
+            ],
+            Finland => bban![
+                n(6, BankCode),
+                n(7, AccountNumber),
+                n(1, NationalCheckDigits)
+            ],
+            France | Monaco => bban![
+                n(5, BankCode),
+                n(5, BranchCode),
+                c(11, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Georgia => bban![c(2, BankCode), n(16, AccountNumber)],
+            Germany => bban![n(8, BankCode), n(10, AccountNumber)],
+            Gibraltar => bban![a(4, BankCode), c(15, AccountNumber)],
+            Greece => bban![n(3, BankCode), n(4, BranchCode), c(16, AccountNumber)],
+            Greenland => bban![n(4, BankCode), n(10, AccountNumber)],
+            Guatemala => bban![c(4, BankCode), c(20, AccountNumber)],
+            Hungary => bban![
+                n(3, BankCode),
+                n(4, BranchCode),
+                n(1, NationalCheckDigits),
+                n(15, AccountNumber),
+                n(1, NationalCheckDigits)
+            ],
+            Iceland => bban![
+                n(2, BankCode),
+                n(2, BranchCode),
+                n(2, AccountType),
+                n(6, AccountNumber),
+                n(10, AccountHolder)
+            ],
+            Internet => bban![c(4, BankCode), c(8, AccountNumber)],
+            Iraq => bban![a(4, BankCode), n(3, BranchCode), n(12, AccountNumber)],
+            Ireland => bban![c(4, BankCode), n(6, BranchCode), n(8, AccountNumber)],
+            Israel => bban![n(3, BankCode), n(3, BranchCode), n(13, AccountNumber)],
+            Italy | SanMarino => bban![
+                a(1, NationalCheckDigits),
+                n(5, BankCode),
+                n(5, BranchCode),
+                c(12, AccountNumber)
+            ],
+            Jordan => bban![a(4, BankCode), n(4, BranchCode), n(18, AccountNumber)],
+            Kazakhstan => bban![n(3, BankCode), c(13, AccountNumber)],
+            Kosovo => bban![
+                n(4, BankCode),
+                n(10, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Kuwait => bban![a(4, BankCode), c(22, AccountNumber)],
+            Latvia => bban![a(4, BankCode), c(13, AccountNumber)],
+            Lebanon => bban![n(4, BankCode), c(20, AccountNumber)],
+            Libya => bban![n(3, BankCode), n(3, BranchCode), n(15, AccountNumber)],
+            Liechenstein => bban![n(5, BankCode), c(12, AccountNumber)],
+            Lithuania => bban![n(5, BankCode), n(11, AccountNumber)],
+            Luxembourg => bban![n(3, BankCode), c(13, AccountNumber)],
+            NorthMacedonia => bban![
+                n(3, BankCode),
+                c(10, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Malta => bban![a(4, BankCode), n(5, BranchCode), c(18, AccountNumber)],
+            Mauritania => bban![
+                n(5, BankCode),
+                n(5, BranchCode),
+                n(11, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Mauritius => bban![
+                a(4, BankCode),
+                n(2, BankCode),
+                n(2, BranchCode),
+                n(12, AccountNumber),
+                n(3, Reserved),
+                a(3, Currency)
+            ],
+            Moldova => bban![c(2, BankCode), c(18, AccountNumber)],
+            Montenegro => bban![
+                n(3, BankCode),
+                n(13, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Netherlands => bban![a(4, BankCode), n(10, AccountNumber)],
+            Norway => bban![
+                n(4, BankCode),
+                n(6, AccountNumber),
+                n(1, NationalCheckDigits)
+            ],
+            Pakistan => bban![c(4, BankCode), n(16, AccountNumber)],
+            PalestinianTerritories => bban![c(4, BankCode), n(21, AccountNumber)],
+            Poland => bban![
+                n(3, BankCode),
+                n(4, BranchCode),
+                n(1, NationalCheckDigits),
+                n(16, AccountNumber)
+            ],
+            Portugal => bban![
+                n(4, BankCode),
+                n(4, BranchCode),
+                n(11, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Qatar => bban![a(4, BankCode), c(21, AccountNumber)],
+            Romania => bban![a(4, BankCode), c(16, AccountNumber)],
+            SaintLucia => bban![a(4, BankCode), c(24, AccountNumber)],
+            SaoTomePrincipe => bban![
+                n(4, BankCode),
+                n(4, BranchCode),
+                n(11, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            SaudiArabia => bban![n(2, BankCode), c(18, AccountNumber)],
+            Serbia => bban![
+                n(3, BankCode),
+                n(13, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Seychelles => bban![
+                a(4, BankCode),
+                n(2, BankCode),
+                n(2, BranchCode),
+                n(16, AccountNumber),
+                a(3, Currency)
+            ],
+            Slovakia => bban![n(4, BankCode), n(16, AccountNumber)],
+            Slovenia => bban![
+                n(2, BankCode),
+                n(3, BranchCode),
+                n(8, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Spain => bban![
+                n(4, BankCode),
+                n(4, BranchCode),
+                n(2, NationalCheckDigits),
+                n(10, AccountNumber)
+            ],
+            Sweden => bban![n(3, BankCode), n(17, AccountNumber)],
+            Switzerland => bban![n(5, BankCode), c(12, AccountNumber)],
+            Tunisia => bban![
+                n(2, BankCode),
+                n(3, BranchCode),
+                n(13, AccountNumber),
+                n(2, NationalCheckDigits)
+            ],
+            Turkey => bban![n(5, BankCode), c(1, Reserved), c(16, AccountNumber)],
+            Ukraine => bban![n(6, BankCode), c(19, AccountNumber)],
+            UnitedArabEmirates => bban![n(3, BankCode), n(16, AccountNumber)],
+            UnitedKingdom => bban![a(4, BankCode), n(6, BranchCode), n(8, AccountNumber)],
+            VaticanCity => bban![n(3, BankCode), n(15, AccountNumber)],
+            VirginIslands => bban![c(4, BankCode), n(16, AccountNumber)],
+        }
+    }
+
+    /// Returns the layout of the BBAN of this country.
+    pub fn bban_spec(&self) -> BbanSpec {
+        BbanSpec {
+            country: *self,
+            segments: self.bban(),
+        }
+    }
+
+    /// Verifies the national check digits of a BBAN, or the other national check of its country.
+    ///
+    /// A BBAN with the wrong length or format fails.
+    pub fn verify_national_check(&self, bban: &str) -> NationalCheck {
+        let spec = self.bban_spec();
+        if bban.len() != spec.length() || self.format_errors(bban.as_bytes()).next().is_some() {
+            return NationalCheck::Failed;
+        }
+
+        match self.national_check(bban) {
+            Some(Ok(())) => NationalCheck::Verified,
+            Some(Err(_)) => NationalCheck::Failed,
+            None if spec.component_length(Component::NationalCheckDigits) > 0 => {
+                NationalCheck::NotVerifiable
+            }
+            None => NationalCheck::NotApplicable,
+        }
+    }
+
+    // The national check digits in a BBAN, if it has them.
+    pub(crate) fn bban_check_digits(&self, bban: &str) -> Option<CheckDigits> {
+        CheckDigits::collect(self.bban_component(bban.as_bytes(), Component::NationalCheckDigits))
+    }
+
+    /// Total length of the given component in the BBAN, 0 if it isn't present.
+    #[cfg(feature = "std")]
+    pub(crate) fn component_length(&self, component: Component) -> usize {
+        self.bban_spec().component_length(component)
+    }
+
+    /// The characters of the given component in an IBAN, if they are all in one piece.
+    pub(crate) fn component_str<'a>(&self, iban: &'a str, component: Component) -> Option<&'a str> {
+        let bban = self.bban();
+        let start = bban.iter().position(|s| s.component == component)?;
+        let count = bban[start..]
+            .iter()
+            .take_while(|s| s.component == component)
+            .count();
+        let (first, rest) = bban[start..].split_at(count);
+        if rest.iter().any(|s| s.component == component) {
+            return None;
+        }
+
+        let last = first.last()?;
+        iban.get(4 + first[0].offset..4 + last.offset + last.length)
+    }
+
+    /// Finds the first character of every segment that doesn't fit it.
+    ///
+    /// A BBAN that is too short is only checked as far as it goes.
+    pub(crate) fn format_errors(
+        self,
+        bban: &[u8],
+    ) -> impl Iterator<Item = (usize, Component)> + '_ {
+        let mut offset = 0;
+        self.bban().iter().filter_map(move |segment| {
+            let start = offset;
+            offset += segment.length;
+
+            let part = bban.get(start..)?.iter().take(segment.length);
+            part.zip(start..)
+                .find(|(byte, _)| !segment.class.matches(**byte as char))
+                .map(|(_, position)| (position, segment.component))
+        })
+    }
+
+    /// Iterates over the characters of the given component in a BBAN.
+    pub(crate) fn bban_component<'a>(
+        self,
+        bban: &'a [u8],
+        component: Component,
+    ) -> impl Iterator<Item = u8> + 'a {
+        let mut offset = 0;
+        self.bban()
+            .iter()
+            .filter_map(move |segment| {
+                let start = offset;
+                offset += segment.length;
+                if segment.component != component {
+                    return None;
+                }
+                bban.get(start..offset)
+            })
+            .flatten()
+            .copied()
+    }
+
+    /// Overwrites the characters of the given component in a BBAN.
+    #[cfg(feature = "std")]
+    pub(crate) fn set_bban_component(&self, bban: &mut String, component: Component, value: &str) {
+        let mut offset = 0;
+        let mut value = value;
+        for segment in self.bban() {
+            if segment.component == component {
+                let (part, rest) = value.split_at(segment.length);
+                bban.replace_range(offset..offset + segment.length, part);
+                value = rest;
+            }
+            offset += segment.length;
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Business Identifier Codes (BIC) as defined by ISO 9362, also known as SWIFT codes.
+
+use crate::{invalid_chars, Country, Iban, ValidationError};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{self, Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+/// A BIC like "DEUTDEFF" or "DEUTDEFF500", returned by [`validate_bic`].
+///
+/// Stored inline in uppercase, so it doesn't allocate and is cheap to copy.
+#[derive(Clone, Copy)]
+pub struct Bic {
+    bytes: [u8; 11],
+    length: usize,
+}
+
+// Territories with their own country code that use the IBANs of another country.
+const TERRITORIES: [(Country, &str); 16] = [
+    (Country::Finland, "AX"),
+    (Country::France, "BL"),
+    (Country::France, "GF"),
+    (Country::France, "GP"),
+    (Country::France, "MF"),
+    (Country::France, "MQ"),
+    (Country::France, "NC"),
+    (Country::France, "PF"),
+    (Country::France, "PM"),
+    (Country::France, "RE"),
+    (Country::France, "TF"),
+    (Country::France, "WF"),
+    (Country::France, "YT"),
+    (Country::UnitedKingdom, "GG"),
+    (Country::UnitedKingdom, "IM"),
+    (Country::UnitedKingdom, "JE"),
+];
+
+impl Bic {
+    /// Returns the institution code, the first 4 characters.
+    pub fn institution_code(&self) -> &str {
+        &self.as_str()[0..4]
+    }
+
+    /// Returns the ISO 3166-1 country code, for example "DE".
+    ///
+    /// This can be a country without IBANs, see [`country`](Bic::country).
+    pub fn country_code(&self) -> &str {
+        &self.as_str()[4..6]
+    }
+
+    /// Returns the country, if it uses IBANs.
+    pub fn country(&self) -> Option<Country> {
+        Country::from_str(self.country_code()).ok()
+    }
+
+    /// Returns the location code, the 2 characters after the country code.
+    pub fn location_code(&self) -> &str {
+        &self.as_str()[6..8]
+    }
+
+    /// Returns the branch code of an 11 character BIC, "XXX" is the primary office.
+    pub fn branch_code(&self) -> Option<&str> {
+        self.as_str().get(8..11)
+    }
+
+    /// Access the BIC as a string, without whitespace and in uppercase.
+    pub fn as_str(&self) -> &str {
+        // Only valid BICs are stored, which are ASCII.
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+
+    /// Checks if `other` is this BIC or one of its branches.
+    ///
+    /// An 8 character BIC matches every branch of the bank, the branch code "XXX" is the
+    /// same as none, so "DEUTDEFFXXX" matches "DEUTDEFF" but not "DEUTDEFF500".
+    pub fn matches(&self, other: &Bic) -> bool {
+        self.as_str()[..8] == other.as_str()[..8]
+            && match (self.branch_code(), other.branch_code()) {
+                (None, _) | (Some("XXX"), None) => true,
+                (Some(branch), Some(other)) => branch == other,
+                (Some(_), None) => false,
+            }
+    }
+
+    /// Checks if this BIC can belong to the bank of the IBAN.
+    ///
+    /// Their countries have to be the same, except for territories using the IBANs of
+    /// another country, like Jersey ("JE") with British IBANs.
+    pub fn is_plausible_for(&self, iban: &Iban) -> bool {
+        let country_code = self.country_code();
+        country_code == iban.country_code()
+            || TERRITORIES
+                .iter()
+                .any(|&(country, code)| country == iban.country && code == country_code)
+    }
+}
+
+/// Checks if the provided string is a valid BIC, or tells you why it isn't.
+///
+/// Whitespace is ignored and lowercase letters are accepted. The country code has to be
+/// letters, but isn't checked against the list of countries. This never panics.
+pub fn validate_bic<I: AsRef<str>>(input: I) -> Result<Bic, ValidationError> {
+    let input = input.as_ref();
+
+    // Remove the whitespace, once we know the rest fits in a BIC.
+    let length = input.split_whitespace().map(str::len).sum();
+    if length > 11 {
+        return Err(ValidationError::InvalidBicLength { length });
+    }
+    let mut bytes = [0; 11];
+    let mut end = 0;
+    for part in input.split_whitespace() {
+        bytes[end..end + part.len()].copy_from_slice(part.as_bytes());
+        end += part.len();
+    }
+
+    // Whole `str`s were copied, so this is valid UTF-8.
+    let compact = core::str::from_utf8(&bytes[..length]).unwrap_or_default();
+    if let Some(error) = invalid_chars(compact).next() {
+        return Err(error);
+    }
+    if length != 8 && length != 11 {
+        return Err(ValidationError::InvalidBicLength { length });
+    }
+    if let Some(position) = (4..6).find(|&i| !bytes[i].is_ascii_alphabetic()) {
+        return Err(ValidationError::InvalidBicFormat { position });
+    }
+
+    bytes.make_ascii_uppercase();
+    Ok(Bic { bytes, length })
+}
+
+impl Debug for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Bic").field(&self.as_str()).finish()
+    }
+}
+
+impl Display for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl PartialEq for Bic {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Bic {}
+
+impl PartialOrd for Bic {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bic {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for Bic {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl AsRef<str> for Bic {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Bic {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate_bic(s)
+    }
+}
+
+impl TryFrom<&str> for Bic {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        validate_bic(value)
+    }
+}
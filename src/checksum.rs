@@ -0,0 +1,67 @@
+//! Computing and repairing the ISO 13616 check digits.
+
+use crate::{Country, Iban, ValidationError};
+
+/// Computes the two check digits of an IBAN with the given country and BBAN.
+///
+/// The result is a number between 2 and 98, to be written as two digits.
+pub fn check_digits(country: Country, bban: &str) -> Result<u8, ValidationError> {
+    if let Some((position, _)) = bban
+        .char_indices()
+        .find(|(_, ch)| !ch.is_ascii_alphanumeric())
+    {
+        return Err(ValidationError::InvalidChar {
+            position: 4 + position,
+        });
+    }
+
+    if bban.len() + 4 != country.length() {
+        return Err(ValidationError::InvalidLength {
+            country,
+            expected: country.length(),
+            actual: bban.len() + 4,
+        });
+    }
+
+    // Same as the mod 97 of the IBAN, with the check digits set to zero.
+    let country_code = country.code().as_bytes();
+    let remainder = remainder(
+        bban.bytes()
+            .chain(country_code.iter().copied())
+            .chain(*b"00"),
+    );
+    Ok((98 - remainder) as u8)
+}
+
+/// Replaces the check digits of an otherwise valid IBAN with the correct ones.
+///
+/// Every other check [`validate`](crate::validate) does still applies, use
+/// [`Iban::check_digits`] to find out what the check digits should have been.
+pub fn repair<I: AsRef<str>>(input: I) -> Result<Iban, ValidationError> {
+    let mut iban = crate::validate_structure(input.as_ref())?;
+
+    let check_digits = check_digits(iban.country, &iban.raw()[4..])?;
+    iban.bytes[2] = b'0' + check_digits / 10;
+    iban.bytes[3] = b'0' + check_digits % 10;
+
+    Ok(iban)
+}
+
+// Computes the ISO 7064 mod 97 remainder of an alphanumeric IBAN.
+pub(crate) fn mod97(input: &str) -> u32 {
+    // Put the country code to the end of the string.
+    let (start, rest) = input.as_bytes().split_at(4);
+    remainder(rest.iter().chain(start.iter()).copied())
+}
+
+// The remainder of the number we get by replacing letters with 10 to 35, one byte at a time.
+pub(crate) fn remainder<I: Iterator<Item = u8>>(bytes: I) -> u32 {
+    bytes.fold(0, |remainder, byte| {
+        if byte.is_ascii_digit() {
+            (remainder * 10 + u32::from(byte - b'0')) % 97
+        } else {
+            let value = u32::from(byte.to_ascii_uppercase().wrapping_sub(b'A')) + 10;
+            (remainder * 100 + value) % 97
+        }
+    })
+}
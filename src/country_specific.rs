@@ -1,64 +1,152 @@
+use crate::bban::{CheckDigits, Component};
+use crate::checksum;
 use crate::country::Country;
 
 impl Country {
-    pub(crate) fn custom_validation(&self, input: &str) -> bool {
+    // Returns the part of the BBAN that failed the check. The national check digits of
+    // the other countries are only reported by `verify_national_check`.
+    pub(crate) fn custom_validation(&self, input: &str) -> Result<(), Component> {
         use Country::*;
         match self {
-            Albania => {
-                let check_digit = self.check_digits(input);
-                let account_number = &input[4..=11];
+            Albania | Belgium | CzechRepublic | France | Monaco | Mauritania | Spain | Italy
+            | SanMarino => self.national_check(&input[4..]).unwrap_or(Ok(())),
+            _ => Ok(()),
+        }
+    }
 
-                let mut total = 0;
-                for (ch, w) in account_number.chars().zip([9, 7, 3, 1, 9, 7, 3, 1].iter()) {
-                    let ch = ch.to_digit(10).unwrap();
-                    total += ch * w;
+    // Runs the national check of a BBAN with the right length and format,
+    // None if we don't know of one for this country.
+    pub(crate) fn national_check(&self, bban: &str) -> Option<Result<(), Component>> {
+        use Country::*;
+        match self {
+            CzechRepublic => {
+                let account_number = &bban[10..];
+                let total = weighted_sum(account_number, &[6, 3, 7, 9, 10, 5, 8, 4, 2, 1]);
+                if total.map(|total| total % 11) != Some(0) {
+                    return Some(Err(Component::AccountNumber));
                 }
 
-                total % 10 == check_digit
+                let branch_number = &bban[4..10];
+                let total = weighted_sum(branch_number, &[10, 5, 8, 4, 2, 1]);
+                if total.map(|total| total % 11) != Some(0) {
+                    return Some(Err(Component::AccountNumber));
+                }
+                Some(Ok(()))
             }
-            Belgium => {
-                let check_digits = self.check_digits(input) as u128;
-                let check_number: u128 = input[4..=13].parse().unwrap();
-                check_number % 97 == check_digits
+            _ => {
+                let expected = self.national_check_digits(bban)?;
+                if self.bban_check_digits(bban) != Some(expected) {
+                    return Some(Err(Component::NationalCheckDigits));
+                }
+                Some(Ok(()))
             }
-            CzechRepublic => {
-                let account_number = &input[14..];
+        }
+    }
 
+    // Computes the national check digits of a BBAN, if we know how.
+    // The check digits already present in the BBAN are ignored.
+    pub(crate) fn national_check_digits(&self, bban: &str) -> Option<CheckDigits> {
+        use Country::*;
+        match self {
+            Albania => {
+                let bank_and_branch = &bban[0..7];
+                let total = weighted_sum(bank_and_branch, &[9, 7, 3, 1, 9, 7, 3])?;
+                Some(CheckDigits::number((10 - total % 10) % 10, 1))
+            }
+            Belgium => {
+                let check_number: u64 = bban[0..10].parse().ok()?;
+                let check_digits = match check_number % 97 {
+                    0 => 97,
+                    x => x,
+                };
+                Some(CheckDigits::number(check_digits as u32, 2))
+            }
+            // One check digit for the bank and branch, one for the account number.
+            Hungary => {
+                let weights = [9, 7, 3, 1, 9, 7, 3, 1, 9, 7, 3, 1, 9, 7, 3];
+                let bank_and_branch = weighted_sum(&bban[0..7], &weights)?;
+                let account_number = weighted_sum(&bban[8..23], &weights)?;
+                let first = (10 - bank_and_branch % 10) % 10;
+                let second = (10 - account_number % 10) % 10;
+                Some(CheckDigits::number(first * 10 + second, 2))
+            }
+            // The CIN, from the values of the characters at odd and even positions.
+            Italy | SanMarino => {
                 let mut total = 0;
-                for (ch, w) in account_number
-                    .chars()
-                    .zip([6, 3, 7, 9, 10, 5, 8, 4, 2, 1].iter())
-                {
-                    let ch = ch.to_digit(10).unwrap();
-                    total += ch * w;
+                for (index, byte) in bban[1..].bytes().enumerate() {
+                    let value = match byte.to_ascii_uppercase() {
+                        digit @ b'0'..=b'9' => digit - b'0',
+                        letter @ b'A'..=b'Z' => letter - b'A',
+                        _ => return None,
+                    };
+                    // Positions are counted from 1, so the first one is odd.
+                    total += match index % 2 {
+                        0 => CIN_ODD[usize::from(value)],
+                        _ => u32::from(value),
+                    };
                 }
-
-                if total % 11 != 0 {
-                    return false;
-                }
-
-                let branch_number = &input[8..=13];
-                let mut total = 0;
-                for (ch, w) in branch_number.chars().zip([10, 5, 8, 4, 2, 1].iter()) {
-                    let ch = ch.to_digit(10).unwrap();
-                    total += ch * w;
+                Some(CheckDigits::letter(b'A' + (total % 26) as u8))
+            }
+            // The control digits of the old CCC, for the bank and branch and for the account.
+            Spain => {
+                let weights = [1, 2, 4, 8, 5, 10, 9, 7, 3, 6];
+                let bank_and_branch = weighted_sum(&bban[0..8], &weights[2..])?;
+                let account_number = weighted_sum(&bban[10..20], &weights)?;
+                let first = ccc_digit(bank_and_branch);
+                let second = ccc_digit(account_number);
+                Some(CheckDigits::number(first * 10 + second, 2))
+            }
+            // The RIB key, with the letters of the account number replaced by digits.
+            France | Monaco | Mauritania => {
+                let rest = bban[..21].bytes().map(rib_digit);
+                let remainder = checksum::remainder(rest.chain(*b"00"));
+                Some(CheckDigits::number(97 - remainder, 2))
+            }
+            // ISO 7064 MOD 97-10 over the rest of the BBAN, which comes before the check digits.
+            BosniaHerzegovina | EastTimor | Kosovo | Montenegro | NorthMacedonia | Portugal
+            | Serbia | Slovenia => {
+                let rest = &bban[..bban.len() - 2];
+                if !rest.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return None;
                 }
-
-                total % 11 == 0
+                let remainder = checksum::remainder(rest.bytes().chain(*b"00"));
+                Some(CheckDigits::number(98 - remainder, 2))
             }
-            _ => true,
+            _ => None,
         }
     }
+}
 
-    pub(crate) fn check_digits(&self, input: &str) -> u32 {
-        use Country::*;
-        let end = input.len() - 1;
-        let (start, stop) = match self {
-            Albania => (12, 12),
-            Belgium | Montenegro => (end - 1, end),
-            _ => unreachable!(),
-        };
+// Values of the digits and letters at odd positions for the Italian CIN,
+// 0 to 9 count like A to J.
+const CIN_ODD: [u32; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+// Multiplies each digit with its weight and adds them up, None if there are other characters.
+fn weighted_sum(digits: &str, weights: &[u32]) -> Option<u32> {
+    let mut total = 0;
+    for (ch, w) in digits.chars().zip(weights.iter()) {
+        total += ch.to_digit(10)? * w;
+    }
+    Some(total)
+}
+
+// Mod 11, 11 becomes 0 and 10 becomes 1 so the result is one digit.
+fn ccc_digit(total: u32) -> u32 {
+    match 11 - total % 11 {
+        11 => 0,
+        10 => 1,
+        digit => digit,
+    }
+}
 
-        input[start..=stop].parse().unwrap()
+// Letters count as digits in RIB keys: A to I are 1 to 9, so are J to R, S to Z are 2 to 9.
+fn rib_digit(byte: u8) -> u8 {
+    match byte.to_ascii_uppercase() {
+        letter @ b'A'..=b'I' => letter - b'A' + b'1',
+        letter @ b'J'..=b'R' => letter - b'J' + b'1',
+        letter @ b'S'..=b'Z' => letter - b'S' + b'2',
+        byte => byte,
     }
 }
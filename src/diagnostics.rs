@@ -0,0 +1,87 @@
+//! Reporting every problem with an IBAN at once.
+
+use crate::{
+    check_checksum, check_country, check_digits_numeric, check_length, check_too_long,
+    detect_country, invalid_chars, invalid_format, ValidationError,
+};
+
+/// How serious a [`Finding`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The IBAN is accepted, but isn't written the way it should be.
+    Warning,
+    /// The IBAN is invalid.
+    Error,
+}
+
+/// A single problem found by [`diagnose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Finding {
+    /// One of the checks [`validate`](crate::validate) does has failed.
+    Invalid(ValidationError),
+    /// A letter is lowercase, the electronic format only uses uppercase.
+    Lowercase {
+        /// Position of the letter, without whitespace.
+        position: usize,
+    },
+}
+
+impl Finding {
+    /// Returns how serious this finding is.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Finding::Invalid(_) => Severity::Error,
+            Finding::Lowercase { .. } => Severity::Warning,
+        }
+    }
+}
+
+/// Runs every applicable check on the input and reports all the problems found.
+///
+/// Unlike [`validate`](crate::validate), this doesn't stop at the first failed check. Checks that
+/// depend on an earlier one, like the country-specific check needing the right
+/// length and format, are skipped when it fails. Valid input has no findings.
+pub fn diagnose<I: AsRef<str>>(input: I) -> Vec<Finding> {
+    let input: String = input.as_ref().split_whitespace().collect();
+
+    let mut errors = Vec::new();
+    errors.extend(check_too_long(input.len()).err());
+
+    let char_errors: Vec<_> = invalid_chars(&input).collect();
+    let alphanumeric = char_errors.is_empty();
+    errors.extend(char_errors);
+    if let Err(error) = check_digits_numeric(&input) {
+        if !errors.contains(&error) {
+            errors.push(error);
+        }
+    }
+
+    match detect_country(&input) {
+        Ok(country) => match check_length(country, &input) {
+            Ok(()) => {
+                let format_errors: Vec<_> = invalid_format(country, &input).collect();
+                let formatted = format_errors.is_empty();
+                errors.extend(format_errors);
+
+                if alphanumeric && formatted {
+                    errors.extend(check_country(country, &input).err());
+                }
+                if alphanumeric {
+                    errors.extend(check_checksum(country, &input).err());
+                }
+            }
+            Err(error) => errors.push(error),
+        },
+        Err(error) => errors.push(error),
+    }
+
+    let mut findings: Vec<Finding> = errors.into_iter().map(Finding::Invalid).collect();
+    findings.extend(
+        input
+            .char_indices()
+            .filter(|(_, ch)| ch.is_lowercase())
+            .map(|(position, _)| Finding::Lowercase { position }),
+    );
+    findings
+}
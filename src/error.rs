@@ -1,40 +1,173 @@
 //! Module holding the error type.
 
-use std::fmt::{self, Display};
+use crate::bban::Component;
+use crate::country::Country;
+use core::fmt::{self, Display};
 
 /// Error type for errors originating from this crate.
-#[derive(Debug)]
+///
+/// Positions are byte offsets into the IBAN in electronic format, without whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum ValidationError {
     /// IBAN can be at most 34 characters long.
-    TooLong,
-    /// IBAN cannot contain non-alphanumeric characters.
-    InvalidChar,
+    TooLong {
+        /// Length of the input without whitespace.
+        length: usize,
+    },
+    /// IBAN cannot contain non-alphanumeric characters, and its check digits have to be digits.
+    InvalidChar {
+        /// Position of the first invalid character.
+        position: usize,
+    },
     /// The IBAN checksum was invalid (remainder of mod 97 was not 1).
-    InvalidIban,
+    InvalidIban {
+        /// The detected country.
+        country: Country,
+        /// The check digits that would make the checksum valid.
+        expected: u8,
+    },
     /// Input didn't contain a supported country code.
     InvalidCountryCode,
     /// IBAN length didn't match the detected country.
-    InvalidLength,
+    InvalidLength {
+        /// The detected country.
+        country: Country,
+        /// Length of IBANs of this country.
+        expected: usize,
+        /// Length of the input without whitespace.
+        actual: usize,
+    },
     /// The format was wrong for the detected country.
-    InvalidFormat,
+    InvalidFormat {
+        /// The detected country.
+        country: Country,
+        /// Position of the first character that doesn't fit the format.
+        position: usize,
+        /// The part of the BBAN this character belongs to.
+        component: Component,
+    },
     /// A custom check this country implements has failed.
-    CountryCheckFailed,
+    CountryCheckFailed {
+        /// The detected country.
+        country: Country,
+        /// The part of the BBAN that failed the check.
+        component: Component,
+    },
+    /// A part of the BBAN is longer than this country allows.
+    InvalidComponentLength {
+        /// The country of the BBAN.
+        country: Country,
+        /// The part of the BBAN that is too long.
+        component: Component,
+        /// Length of this part for this country.
+        expected: usize,
+        /// Length of the provided part.
+        actual: usize,
+    },
+    /// A BIC has to be 8 or 11 characters long.
+    InvalidBicLength {
+        /// Length of the input without whitespace.
+        length: usize,
+    },
+    /// The BIC has the wrong format, the country code isn't letters.
+    InvalidBicFormat {
+        /// Position of the first character that doesn't fit the format.
+        position: usize,
+    },
+    /// The bank code isn't assigned to any bank, according to the bank registry.
+    UnknownBank {
+        /// The detected country.
+        country: Country,
+    },
+    /// The requested operation isn't supported for this part of the BBAN.
+    Unsupported {
+        /// The country of the BBAN.
+        country: Country,
+        /// The part of the BBAN that isn't supported.
+        component: Component,
+    },
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ValidationError {}
 
 impl Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use ValidationError::*;
-        let msg = match self {
-            TooLong => "Input is longer than 34 characters.",
-            InvalidChar => "Input contains at least one invalid character.",
-            InvalidIban => "IBAN mod 97 checksum is invalid.",
-            InvalidCountryCode => "Input doesn't contain a supported country.",
-            InvalidLength => "Input is invalid length for the detected country.",
-            InvalidFormat => "IBAN has the wrong format for the detected country.",
-            CountryCheckFailed => "Failed custom country-specific check.",
-        };
-        write!(f, "{}", msg)
+        match self {
+            TooLong { length } => write!(
+                f,
+                "Input is {} characters long, more than the 34 allowed.",
+                length
+            ),
+            InvalidChar { position } => write!(
+                f,
+                "Input contains an invalid character at position {}.",
+                position
+            ),
+            InvalidIban { expected, .. } => write!(
+                f,
+                "IBAN mod 97 checksum is invalid, the check digits should be {:02}.",
+                expected
+            ),
+            InvalidCountryCode => write!(f, "Input doesn't contain a supported country."),
+            InvalidLength {
+                country,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Input is {} characters long, {} IBANs are {}.",
+                actual, country, expected
+            ),
+            InvalidFormat {
+                country,
+                position,
+                component,
+            } => write!(
+                f,
+                "IBAN has the wrong format for {}, the {} is invalid at position {}.",
+                country, component, position
+            ),
+            CountryCheckFailed { component, .. } => write!(
+                f,
+                "Failed custom country-specific check of the {}.",
+                component
+            ),
+            InvalidComponentLength {
+                country,
+                component,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The {} is {} characters long, {} allows at most {}.",
+                component, actual, country, expected
+            ),
+            InvalidBicLength { length } => write!(
+                f,
+                "BIC is {} characters long, it should be 8 or 11.",
+                length
+            ),
+            InvalidBicFormat { position } => write!(
+                f,
+                "BIC has the wrong format, the country code is invalid at position {}.",
+                position
+            ),
+            UnknownBank { country } => {
+                write!(
+                    f,
+                    "The bank code isn't assigned to any bank in {}.",
+                    country
+                )
+            }
+            Unsupported { country, component } => write!(
+                f,
+                "Operation is not supported for the {} of {}.",
+                component, country
+            ),
+        }
     }
 }
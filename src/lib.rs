@@ -9,6 +9,14 @@
 //!assert!(schwifty::validate("GB82 WEST 1234 5698 7654 32").is_ok());
 //! ```
 //!
+//! ## Features
+//! - `std` (default): everything that allocates, like `diagnose`, `suggest_corrections`,
+//!   `Iban::from_parts` and loading bank registries or modulus rules from files. Without
+//!   it the crate is `no_std` and doesn't need `alloc`, [`validate`] and [`validate_ref`]
+//!   are still available.
+//! - `serde`: (de)serializing [`Iban`], [`Bic`] and [`Country`] as strings, deserializing
+//!   validates them. [`ValidationError`] can be (de)serialized as well.
+//!
 //! [ci]: https://github.com/Elinvynia/schwifty/actions?query=workflow%3ARust
 //! [ci-badge]: https://img.shields.io/github/workflow/status/Elinvynia/schwifty/Rust/master?style=flat-square
 //! [docs]: https://docs.rs/schwifty
@@ -16,128 +24,592 @@
 //! [crate-link]: https://crates.io/crates/schwifty
 //! [crate-version]: https://img.shields.io/crates/v/schwifty.svg?style=flat-square
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
+use crate::bban::{Component, NationalCheck};
+pub use crate::bic::{validate_bic, Bic};
 pub use crate::country::Country;
+#[cfg(feature = "std")]
+pub use crate::diagnostics::{diagnose, Finding, Severity};
 pub use crate::error::ValidationError;
-use std::str::FromStr;
+pub use crate::partial::{validate_partial, Partial};
+#[cfg(feature = "std")]
+pub use crate::suggest::suggest_corrections;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{self, Debug, Display, Write};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+pub mod bban;
+pub mod bic;
+pub mod checksum;
 pub mod country;
 pub(crate) mod country_specific;
+#[cfg(feature = "std")]
+pub(crate) mod diagnostics;
 pub mod error;
-
-#[allow(clippy::all)]
-pub(crate) mod u256 {
-    uint::construct_uint! {
-        pub(crate) struct U256(4);
-    }
-}
+#[cfg(feature = "std")]
+pub mod modulus;
+pub(crate) mod partial;
+pub mod registry;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "std")]
+pub(crate) mod suggest;
 
 /// Represents an IBAN and provides helpful methods.
-#[derive(Debug)]
+///
+/// The IBAN is stored inline in electronic format, so it doesn't allocate and is cheap to copy.
+/// Comparing, ordering and hashing use the electronic format.
+#[derive(Clone, Copy)]
 #[non_exhaustive]
 pub struct Iban {
     /// The country of this IBAN.
     pub country: Country,
-    pub(crate) raw: String,
+    bytes: [u8; 34],
+    length: usize,
 }
 
 impl Iban {
+    /// Builds an IBAN from its national parts, computing the check digits.
+    ///
+    /// Parts shorter than their place in the BBAN are padded with leading zeros,
+    /// pass an empty string for countries without a branch code. Letters are uppercased.
+    /// National check digits are computed when this crate knows how to. If they can't be,
+    /// or the BBAN has other parts like an account type, [`ValidationError::Unsupported`]
+    /// is returned.
+    #[cfg(feature = "std")]
+    pub fn from_parts(
+        country: Country,
+        bank_code: &str,
+        branch_code: &str,
+        account_number: &str,
+    ) -> Result<Iban, ValidationError> {
+        use crate::bban::Component::*;
+
+        let bank_code = pad(country, BankCode, &bank_code.to_ascii_uppercase())?;
+        let branch_code = pad(country, BranchCode, &branch_code.to_ascii_uppercase())?;
+        let account_number = pad(country, AccountNumber, &account_number.to_ascii_uppercase())?;
+
+        let mut bank_code = bank_code.chars();
+        let mut branch_code = branch_code.chars();
+        let mut account_number = account_number.chars();
+        let mut bban = String::with_capacity(30);
+        for segment in country.bban() {
+            let part = match segment.component {
+                BankCode => &mut bank_code,
+                BranchCode => &mut branch_code,
+                AccountNumber => &mut account_number,
+                // Check digits are computed below, reserved places are always zero.
+                NationalCheckDigits | Reserved => {
+                    bban.push_str(&"0".repeat(segment.length));
+                    continue;
+                }
+                component => return Err(ValidationError::Unsupported { country, component }),
+            };
+            for ch in part.take(segment.length) {
+                if !segment.class.matches(ch) {
+                    return Err(ValidationError::InvalidFormat {
+                        country,
+                        position: 4 + bban.len(),
+                        component: segment.component,
+                    });
+                }
+                bban.push(ch);
+            }
+        }
+
+        if country.component_length(NationalCheckDigits) > 0 {
+            let check_digits =
+                country
+                    .national_check_digits(&bban)
+                    .ok_or(ValidationError::Unsupported {
+                        country,
+                        component: NationalCheckDigits,
+                    })?;
+            country.set_bban_component(&mut bban, NationalCheckDigits, check_digits.as_str());
+        }
+
+        let check_digits = checksum::check_digits(country, &bban)?;
+        validate(format!("{}{:02}{}", country, check_digits, bban))
+    }
+
     /// Returns the account number of the IBAN.
-    pub fn account_number(&self) -> String {
-        self.country.account_number(&self.raw)
+    pub fn account_number(&self) -> &str {
+        self.country
+            .component_str(self.raw(), Component::AccountNumber)
+            .unwrap_or_default()
     }
 
     /// Returns the national bank code of the IBAN.
-    pub fn bank_code(&self) -> String {
-        self.country.bank_code(&self.raw)
+    pub fn bank_code(&self) -> &str {
+        self.country
+            .component_str(self.raw(), Component::BankCode)
+            .unwrap_or_default()
+    }
+
+    /// Returns the branch code of the IBAN, like a UK sort code, if its country has one.
+    pub fn branch_code(&self) -> Option<&str> {
+        self.country
+            .component_str(self.raw(), Component::BranchCode)
+    }
+
+    /// Looks up the bank of this IBAN in the given registry.
+    pub fn bank_in<'a, R: registry::BankRegistry + ?Sized>(
+        &'a self,
+        registry: &'a R,
+    ) -> Option<registry::Bank<'a>> {
+        registry.lookup(self.country, self.bank_code())
+    }
+
+    /// Returns the national check digits in the BBAN, if its country has them.
+    ///
+    /// None for Hungary, where they aren't next to each other.
+    pub fn national_check_digits(&self) -> Option<&str> {
+        self.country
+            .component_str(self.raw(), Component::NationalCheckDigits)
+    }
+
+    /// Tells if the national check digits were verified, see [`Country::verify_national_check`].
+    ///
+    /// [`validate`] already rejects IBANs that fail it in Albania, Belgium, the Czech
+    /// Republic, France, Italy, Mauritania, Monaco, San Marino and Spain.
+    pub fn verify_national_check(&self) -> NationalCheck {
+        self.country.verify_national_check(&self.raw()[4..])
+    }
+
+    /// Returns the two check digits of the IBAN, for example "82".
+    pub fn check_digits(&self) -> &str {
+        &self.raw()[2..4]
     }
 
-    /// Returns the country code as a String, for example "GB".
-    pub fn country_code(&self) -> String {
-        self.country.to_string()
+    /// Returns the country code, for example "GB".
+    pub fn country_code(&self) -> &'static str {
+        self.country.code()
     }
 
-    /// Access the raw String this IBAN was made from.
+    /// Access the IBAN in electronic format, without whitespace.
     pub fn raw(&self) -> &str {
-        &self.raw
+        // Only valid IBANs are stored, which are ASCII.
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_default()
+    }
+
+    /// Returns the IBAN in print format, in groups of four separated by spaces.
+    ///
+    /// Same as `to_string()`, use [`raw`](Iban::raw) for the electronic format.
+    #[cfg(feature = "std")]
+    pub fn to_print_format(&self) -> String {
+        self.grouped(" ", 4).to_string()
+    }
+
+    /// Displays the IBAN in groups of `size` characters, separated by `separator`.
+    ///
+    /// The last group is shorter if the length isn't a multiple of `size`, a `size` of 0
+    /// doesn't group at all.
+    pub fn grouped<'a>(&'a self, separator: &'a str, size: usize) -> Grouped<'a> {
+        Grouped {
+            iban: self.raw(),
+            separator,
+            size,
+        }
+    }
+}
+
+/// Displays an IBAN in groups, returned by [`Iban::grouped`].
+#[derive(Debug, Clone, Copy)]
+pub struct Grouped<'a> {
+    iban: &'a str,
+    separator: &'a str,
+    size: usize,
+}
+
+impl Grouped<'_> {
+    fn write_groups(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.size == 0 {
+            return f.write_str(self.iban);
+        }
+
+        for (i, group) in self.iban.as_bytes().chunks(self.size).enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            // IBANs are ASCII, so every group is valid UTF-8.
+            f.write_str(core::str::from_utf8(group).unwrap_or_default())?;
+        }
+        Ok(())
+    }
+}
+
+// Pads to the width like `Formatter::pad`, which needs the whole string at once.
+impl Display for Grouped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separators = match self.size {
+            0 => 0,
+            size => self.iban.len().saturating_sub(1) / size,
+        };
+        let length = self.iban.len() + separators * self.separator.chars().count();
+        let padding = f.width().unwrap_or(0).saturating_sub(length);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        self.write_groups(f)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Iban")
+            .field("country", &self.country)
+            .field("raw", &self.raw())
+            .finish()
+    }
+}
+
+// Print format by default, `{:#}` gives the electronic format.
+impl Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.pad(self.raw())
+        } else {
+            Display::fmt(&self.grouped(" ", 4), f)
+        }
+    }
+}
+
+impl PartialEq for Iban {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw() == other.raw()
+    }
+}
+
+impl Eq for Iban {}
+
+impl PartialOrd for Iban {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Iban {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw().cmp(other.raw())
+    }
+}
+
+impl Hash for Iban {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw().hash(state);
+    }
+}
+
+impl AsRef<str> for Iban {
+    fn as_ref(&self) -> &str {
+        self.raw()
+    }
+}
+
+impl FromStr for Iban {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)
+    }
+}
+
+impl TryFrom<&str> for Iban {
+    type Error = ValidationError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        validate(value)
+    }
+}
+
+/// A valid IBAN borrowed from the input, returned by [`validate_ref`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IbanRef<'a> {
+    /// The country of this IBAN.
+    pub country: Country,
+    raw: &'a str,
+}
+
+impl<'a> IbanRef<'a> {
+    /// Returns the account number of the IBAN.
+    pub fn account_number(&self) -> &'a str {
+        self.country
+            .component_str(self.raw, Component::AccountNumber)
+            .unwrap_or_default()
+    }
+
+    /// Returns the national bank code of the IBAN.
+    pub fn bank_code(&self) -> &'a str {
+        self.country
+            .component_str(self.raw, Component::BankCode)
+            .unwrap_or_default()
+    }
+
+    /// Returns the branch code of the IBAN, like a UK sort code, if its country has one.
+    pub fn branch_code(&self) -> Option<&'a str> {
+        self.country.component_str(self.raw, Component::BranchCode)
+    }
+
+    /// Returns the national check digits in the BBAN, if its country has them.
+    ///
+    /// None for Hungary, where they aren't next to each other.
+    pub fn national_check_digits(&self) -> Option<&'a str> {
+        self.country
+            .component_str(self.raw, Component::NationalCheckDigits)
+    }
+
+    /// Tells if the national check digits were verified, see [`Country::verify_national_check`].
+    pub fn verify_national_check(&self) -> NationalCheck {
+        self.country.verify_national_check(&self.raw[4..])
+    }
+
+    /// Returns the two check digits of the IBAN, for example "82".
+    pub fn check_digits(&self) -> &'a str {
+        &self.raw[2..4]
+    }
+
+    /// Returns the country code, for example "GB".
+    pub fn country_code(&self) -> &'static str {
+        self.country.code()
+    }
+
+    /// Access the input this IBAN was validated from.
+    pub fn raw(&self) -> &'a str {
+        self.raw
     }
 }
 
 /// Checks if the provided string is a valid IBAN, or tells you why it isn't.
+///
+/// Whitespace is ignored, any other character than the ASCII letters and digits is
+/// rejected with [`ValidationError::InvalidChar`]. Lowercase letters the format allows
+/// are stored in uppercase. This never panics, whatever the input.
 pub fn validate<I: AsRef<str>>(input: I) -> Result<Iban, ValidationError> {
-    let input = input.as_ref();
+    let iban = validate_structure(input.as_ref())?;
+    check_checksum(iban.country, iban.raw())?;
+    Ok(iban)
+}
 
-    // Remove the whitespace.
-    let input: String = input.split_whitespace().collect();
+/// Validates the IBAN like [`validate`], then checks that its bank code is in use.
+///
+/// Returns [`ValidationError::UnknownBank`] if the registry lists every bank of the
+/// country, see [`BankRegistry::is_complete`](registry::BankRegistry::is_complete), but
+/// not this bank code. If the bank is found, the account number is checked with
+/// [`Bank::verify_account_number`](registry::Bank::verify_account_number), which needs a
+/// registry loaded from the bank directory of the Bundesbank for German IBANs.
+pub fn validate_with_registry<I, R>(input: I, registry: &R) -> Result<Iban, ValidationError>
+where
+    I: AsRef<str>,
+    R: registry::BankRegistry + ?Sized,
+{
+    let iban = validate(input)?;
+    let bank = match iban.bank_in(registry) {
+        Some(bank) => bank,
+        None if registry.is_complete(iban.country) => {
+            return Err(ValidationError::UnknownBank {
+                country: iban.country,
+            })
+        }
+        None => return Ok(iban),
+    };
+    if bank.verify_account_number(iban.account_number()) == NationalCheck::Failed {
+        return Err(ValidationError::CountryCheckFailed {
+            country: iban.country,
+            component: Component::AccountNumber,
+        });
+    }
+    Ok(iban)
+}
 
-    // IBAN can be at most 34 characters(bytes) long.
-    if input.len() > 34 {
-        return Err(ValidationError::TooLong);
+/// Checks an IBAN in electronic format without allocating, borrowing it if it is valid.
+///
+/// Works on both `&str` and `&[u8]`. Unlike [`validate`] whitespace isn't
+/// allowed, so `"GB82WEST12345698765432"` is valid but `"GB82 WEST 1234 5698 7654 32"` isn't.
+/// This never panics, whatever the input.
+pub fn validate_ref<I: AsRef<[u8]> + ?Sized>(input: &I) -> Result<IbanRef<'_>, ValidationError> {
+    let bytes = input.as_ref();
+    let input = match core::str::from_utf8(bytes) {
+        Ok(input) => input,
+        Err(error) => {
+            // Report the first invalid character, which is at the latest the byte that isn't UTF-8.
+            check_too_long(bytes.len())?;
+            let position = error.valid_up_to();
+            let valid = core::str::from_utf8(&bytes[..position]).unwrap_or_default();
+            let error = invalid_chars(valid).next();
+            return Err(error.unwrap_or(ValidationError::InvalidChar { position }));
+        }
     };
 
-    // All of the characters must be alphanumeric.
-    if !input.chars().all(|ch| ch.is_alphanumeric()) {
-        return Err(ValidationError::InvalidChar);
+    let country = check_structure(input)?;
+    check_checksum(country, input)?;
+    Ok(IbanRef {
+        country,
+        raw: input,
+    })
+}
+
+// Runs every check except for the mod 97 checksum.
+pub(crate) fn validate_structure(input: &str) -> Result<Iban, ValidationError> {
+    let (mut bytes, length) = compact(input)?;
+
+    // Whole `str`s were copied, so this is valid UTF-8.
+    let input = core::str::from_utf8(&bytes[..length]).unwrap_or_default();
+    let country = check_structure(input)?;
+    // Stored in uppercase, so IBANs typed in either case compare and hash the same.
+    bytes[..length].make_ascii_uppercase();
+    Ok(Iban {
+        country,
+        bytes,
+        length,
+    })
+}
+
+// Removes the whitespace, once we know the rest fits in an IBAN.
+pub(crate) fn compact(input: &str) -> Result<([u8; 34], usize), ValidationError> {
+    check_too_long(input.split_whitespace().map(str::len).sum())?;
+    let mut bytes = [0; 34];
+    let mut length = 0;
+    for part in input.split_whitespace() {
+        bytes[length..length + part.len()].copy_from_slice(part.as_bytes());
+        length += part.len();
+    }
+    Ok((bytes, length))
+}
+
+// Same as `validate_structure`, for input without whitespace.
+fn check_structure(input: &str) -> Result<Country, ValidationError> {
+    check_too_long(input.len())?;
+    if let Some(error) = invalid_chars(input).next() {
+        return Err(error);
+    }
+    let country = detect_country(input)?;
+    check_digits_numeric(input)?;
+    check_length(country, input)?;
+    if let Some(error) = invalid_format(country, input).next() {
+        return Err(error);
+    }
+    check_country(country, input)?;
+    Ok(country)
+}
+
+// The individual checks, `diagnose` runs all of them instead of stopping at the first.
+// They all expect input without whitespace.
+
+pub(crate) fn check_too_long(length: usize) -> Result<(), ValidationError> {
+    // IBAN can be at most 34 characters(bytes) long.
+    if length > 34 {
+        return Err(ValidationError::TooLong { length });
     };
+    Ok(())
+}
+
+pub(crate) fn invalid_chars(input: &str) -> impl Iterator<Item = ValidationError> + '_ {
+    // All of the characters must be ASCII alphanumeric, which also makes slicing by byte safe.
+    input
+        .char_indices()
+        .filter(|(_, ch)| !ch.is_ascii_alphanumeric())
+        .map(|(position, _)| ValidationError::InvalidChar { position })
+}
 
+pub(crate) fn check_digits_numeric(input: &str) -> Result<(), ValidationError> {
+    // The two check digits after the country code can only be digits.
+    match input
+        .bytes()
+        .enumerate()
+        .skip(2)
+        .take(2)
+        .find(|(_, byte)| !byte.is_ascii_digit())
+    {
+        Some((position, _)) => Err(ValidationError::InvalidChar { position }),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn detect_country(input: &str) -> Result<Country, ValidationError> {
     // IBAN must have at least 2 characters to match a country code.
-    if input.len() < 2 {
-        return Err(ValidationError::InvalidCountryCode);
-    };
+    let country_code = input.get(0..2).ok_or(ValidationError::InvalidCountryCode)?;
 
     // See if it is a valid Country
-    let country_code = &input[0..2];
-    let country = match Country::from_str(country_code) {
-        Ok(c) => c,
-        Err(_) => return Err(ValidationError::InvalidCountryCode),
-    };
+    Country::from_str(country_code).map_err(|_| ValidationError::InvalidCountryCode)
+}
 
+pub(crate) fn check_length(country: Country, input: &str) -> Result<(), ValidationError> {
     // Since it is a valid country, check if it is the proper length.
     if input.len() != country.length() {
-        return Err(ValidationError::InvalidLength);
+        return Err(ValidationError::InvalidLength {
+            country,
+            expected: country.length(),
+            actual: input.len(),
+        });
     }
+    Ok(())
+}
 
-    // Also check if the format matches
-    if !country.format().is_match(&input) {
-        return Err(ValidationError::InvalidFormat);
-    }
+// Expects input of the proper length.
+pub(crate) fn invalid_format(
+    country: Country,
+    input: &str,
+) -> impl Iterator<Item = ValidationError> + '_ {
+    // Also check if the format matches each segment of the BBAN.
+    country
+        .format_errors(&input.as_bytes()[4..])
+        .map(
+            move |(position, component)| ValidationError::InvalidFormat {
+                country,
+                position: 4 + position,
+                component,
+            },
+        )
+}
 
+// Expects input of the proper length and format.
+pub(crate) fn check_country(country: Country, input: &str) -> Result<(), ValidationError> {
     // Do country-specifich checks.
-    if !country.custom_validation(&input) {
-        return Err(ValidationError::CountryCheckFailed);
-    }
-
-    // Put the country code to the end of the string.
-    let (start, rest) = input.split_at(4);
-    let mut rearranged = String::with_capacity(34);
-    rearranged.push_str(rest);
-    rearranged.push_str(start);
+    country
+        .custom_validation(input)
+        .map_err(|component| ValidationError::CountryCheckFailed { country, component })
+}
 
-    // Convert ASCII letters to their code, don't modify numbers.
-    let mut integer_string = String::with_capacity(34);
-    for ch in rearranged.chars() {
-        if ch.is_numeric() {
-            integer_string.push(ch);
-        } else {
-            // This will not panic as we are guaranteed A-Z, a-z
-            let x = ch.to_digit(36).unwrap().to_string();
-            integer_string.push_str(&x)
-        }
+// Expects alphanumeric input of the proper length.
+pub(crate) fn check_checksum(country: Country, input: &str) -> Result<(), ValidationError> {
+    // Make sure that the remainder is one.
+    if checksum::mod97(input) != 1 {
+        let expected = checksum::check_digits(country, &input[4..])?;
+        return Err(ValidationError::InvalidIban { country, expected });
     }
+    Ok(())
+}
 
-    // This will not panic as u256 can hold any IBAN.
-    let integer = u256::U256::from_dec_str(&integer_string).unwrap();
-
-    // Make sure that the remainder is one.
-    if integer % 97 != 1.into() {
-        return Err(ValidationError::InvalidIban);
+// Left-pads a part of the BBAN with zeros to its length.
+#[cfg(feature = "std")]
+fn pad(country: Country, component: Component, part: &str) -> Result<String, ValidationError> {
+    let length = country.component_length(component);
+    if part.len() > length {
+        return Err(ValidationError::InvalidComponentLength {
+            country,
+            component,
+            expected: length,
+            actual: part.len(),
+        });
     }
 
-    Ok(Iban {
-        country,
-        raw: input,
-    })
+    Ok(format!("{:0>1$}", part, length))
 }
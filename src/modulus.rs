@@ -0,0 +1,281 @@
+//! Modulus checking of British sort codes and account numbers, as specified by VocaLink.
+//!
+//! The weights of each sort code range are published in the `valacdos` file, some sort
+//! codes are substituted as listed in the `scsubtab` file. Neither is bundled, they
+//! change a few times a year.
+
+use crate::bban::NationalCheck;
+use crate::registry::ParseError;
+use crate::{validate, Country, Iban, ValidationError};
+use core::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Mod10,
+    Mod11,
+    DoubleAlternate,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    start: u32,
+    end: u32,
+    method: Method,
+    // For the 6 digits of the sort code, then the 8 of the account number.
+    weights: [i32; 14],
+    exception: Option<u8>,
+}
+
+// Positions of the digits named in the specification: u to z are the sort code,
+// a to h the account number.
+const A: usize = 6;
+const B: usize = 7;
+const C: usize = 8;
+const G: usize = 12;
+const H: usize = 13;
+
+/// The weights and exceptions used to check British account numbers.
+#[derive(Debug, Clone, Default)]
+pub struct ModulusRules {
+    rules: Vec<Rule>,
+    substitutions: Vec<(u32, u32)>,
+}
+
+impl ModulusRules {
+    /// Parses the `valacdos` file.
+    ///
+    /// Each line has the first and last sort code of a range, the method (`MOD10`,
+    /// `MOD11` or `DBLAL`), 14 weights and optionally an exception code. Sort codes with
+    /// two checks have two lines.
+    pub fn from_valacdos(content: &str) -> Result<ModulusRules, ParseError> {
+        let mut rules = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            let rule = parse_rule(&fields).ok_or(ParseError::InvalidLine { line: index + 1 })?;
+            rules.push(rule);
+        }
+        Ok(ModulusRules {
+            rules,
+            substitutions: Vec::new(),
+        })
+    }
+
+    /// Adds the sort code substitutions of the `scsubtab` file, used by exception 5.
+    ///
+    /// Each line has a sort code and the sort code to check it with.
+    pub fn with_substitutions(mut self, scsubtab: &str) -> Result<ModulusRules, ParseError> {
+        for (index, line) in scsubtab.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            let invalid = ParseError::InvalidLine { line: index + 1 };
+            let (original, substitute) = match fields[..] {
+                [original, substitute] => (sort_code(original), sort_code(substitute)),
+                _ => return Err(invalid),
+            };
+            match original.zip(substitute) {
+                Some(substitution) => self.substitutions.push(substitution),
+                None => return Err(invalid),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Checks a 6 digit sort code and an 8 digit account number.
+    ///
+    /// Sort codes that aren't in the `valacdos` file can't be checked. Neither can
+    /// foreign currency accounts under exception 6.
+    pub fn verify(&self, sort_code: &str, account_number: &str) -> NationalCheck {
+        if sort_code.len() != 6 || account_number.len() != 8 {
+            return NationalCheck::Failed;
+        }
+        let mut digits = [0; 14];
+        let input = sort_code.chars().chain(account_number.chars());
+        for (digit, ch) in digits.iter_mut().zip(input) {
+            match ch.to_digit(10) {
+                Some(value) => *digit = value as i32,
+                None => return NationalCheck::Failed,
+            }
+        }
+
+        let value = digits[..6]
+            .iter()
+            .fold(0, |value, &digit| value * 10 + digit as u32);
+        let rules: Vec<&Rule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.start <= value && value <= rule.end)
+            .take(2)
+            .collect();
+        let exception = match rules.first() {
+            Some(rule) => rule.exception,
+            None => return NationalCheck::NotVerifiable,
+        };
+
+        match exception {
+            // The second check is only needed if the first fails, with sort code 309634.
+            Some(2) => {
+                if rules[0].check(&digits) {
+                    return NationalCheck::Verified;
+                }
+                set_sort_code(&mut digits, 309634);
+                return match rules.get(1) {
+                    Some(rule) if rule.check(&digits) => NationalCheck::Verified,
+                    _ => NationalCheck::Failed,
+                };
+            }
+            // Retried without the last digit, if it is 0, 1 or 9.
+            Some(14) if !rules[0].check(&digits) && matches!(digits[H], 0 | 1 | 9) => {
+                digits.copy_within(A..H, B);
+                digits[A] = 0;
+            }
+            Some(6) if (4..=8).contains(&digits[A]) && digits[G] == digits[H] => {
+                return NationalCheck::NotVerifiable
+            }
+            // Checked as if it was another sort code.
+            Some(5) => {
+                if let Some(&(_, substitute)) = self.substitutions.iter().find(|s| s.0 == value) {
+                    set_sort_code(&mut digits, substitute);
+                }
+            }
+            Some(8) => set_sort_code(&mut digits, 90126),
+            _ => {}
+        }
+
+        let mut passed = rules.iter().map(|rule| rule.check(&digits));
+        // One of the two checks has to pass with these exceptions, otherwise both.
+        let any = rules
+            .iter()
+            .any(|rule| matches!(rule.exception, Some(10..=13)));
+        let valid = if any {
+            passed.any(|passed| passed)
+        } else {
+            passed.all(|passed| passed)
+        };
+        if valid {
+            NationalCheck::Verified
+        } else {
+            NationalCheck::Failed
+        }
+    }
+
+    /// Checks the sort code and account number of a British IBAN.
+    ///
+    /// IBANs of other countries are [`NationalCheck::NotApplicable`].
+    pub fn verify_iban(&self, iban: &Iban) -> NationalCheck {
+        match (iban.country, iban.branch_code()) {
+            (Country::UnitedKingdom, Some(sort_code)) => {
+                self.verify(sort_code, iban.account_number())
+            }
+            _ => NationalCheck::NotApplicable,
+        }
+    }
+
+    /// Validates the IBAN like [`validate`], then checks British IBANs with
+    /// [`verify_iban`](ModulusRules::verify_iban).
+    ///
+    /// Failing the modulus check is reported as [`ValidationError::CountryCheckFailed`].
+    pub fn validate<I: AsRef<str>>(&self, input: I) -> Result<Iban, ValidationError> {
+        let iban = validate(input)?;
+        if self.verify_iban(&iban) == NationalCheck::Failed {
+            return Err(ValidationError::CountryCheckFailed {
+                country: iban.country,
+                component: crate::bban::Component::AccountNumber,
+            });
+        }
+        Ok(iban)
+    }
+}
+
+impl Rule {
+    fn check(&self, digits: &[i32; 14]) -> bool {
+        let mut weights = self.weights;
+        match self.exception {
+            // Zeroise the weights of the sort code and the first two digits of the account.
+            Some(7) if digits[G] == 9 => weights[..8].iter_mut().for_each(|w| *w = 0),
+            Some(2) if digits[A] != 0 && digits[G] == 9 => {
+                weights = [0, 0, 0, 0, 0, 0, 0, 0, 8, 7, 10, 9, 3, 1]
+            }
+            Some(2) if digits[A] != 0 => weights = [0, 0, 1, 2, 5, 3, 6, 4, 8, 7, 10, 9, 3, 1],
+            Some(10) if matches!((digits[A], digits[B]), (0, 9) | (9, 9)) && digits[G] == 9 => {
+                weights[..8].iter_mut().for_each(|w| *w = 0)
+            }
+            Some(3) if self.method == Method::DoubleAlternate && matches!(digits[C], 6 | 9) => {
+                return true
+            }
+            _ => {}
+        }
+
+        let products = digits.iter().zip(weights.iter()).map(|(d, w)| d * w);
+        let mut total: i32 = match self.method {
+            Method::DoubleAlternate => products.map(|p| p / 10 + p % 10).sum(),
+            _ => products.sum(),
+        };
+        if self.exception == Some(1) {
+            total += 27;
+        }
+
+        match (self.method, self.exception) {
+            // The remainder is the last two digits of the account number.
+            (Method::Mod11, Some(4)) => total % 11 == digits[G] * 10 + digits[H],
+            // The remainder gives the check digit g, or h for the second check.
+            (Method::Mod11, Some(5)) => match total % 11 {
+                0 => digits[G] == 0,
+                1 => false,
+                remainder => digits[G] == 11 - remainder,
+            },
+            (Method::DoubleAlternate, Some(5)) => match total % 10 {
+                0 => digits[H] == 0,
+                remainder => digits[H] == 10 - remainder,
+            },
+            (Method::Mod11, _) => total % 11 == 0,
+            _ => total % 10 == 0,
+        }
+    }
+}
+
+fn parse_rule(fields: &[&str]) -> Option<Rule> {
+    if fields.len() != 17 && fields.len() != 18 {
+        return None;
+    }
+    let method = match fields[2] {
+        "MOD10" => Method::Mod10,
+        "MOD11" => Method::Mod11,
+        "DBLAL" => Method::DoubleAlternate,
+        _ => return None,
+    };
+    let mut weights = [0; 14];
+    for (weight, field) in weights.iter_mut().zip(&fields[3..17]) {
+        *weight = i32::from_str(field).ok()?;
+    }
+    let exception = match fields.get(17) {
+        Some(field) => Some(u8::from_str(field).ok()?),
+        None => None,
+    };
+    Some(Rule {
+        start: sort_code(fields[0])?,
+        end: sort_code(fields[1])?,
+        method,
+        weights,
+        exception,
+    })
+}
+
+fn sort_code(field: &str) -> Option<u32> {
+    if field.len() != 6 || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    u32::from_str(field).ok()
+}
+
+fn set_sort_code(digits: &mut [i32; 14], sort_code: u32) {
+    let mut value = sort_code;
+    for digit in digits[..6].iter_mut().rev() {
+        *digit = (value % 10) as i32;
+        value /= 10;
+    }
+}
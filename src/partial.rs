@@ -0,0 +1,93 @@
+//! Checking an IBAN while it is being typed.
+
+use crate::{
+    check_checksum, check_country, check_digits_numeric, check_length, compact, detect_country,
+    invalid_chars, Country, Iban, ValidationError,
+};
+use core::str::FromStr;
+
+/// The state of a partially typed IBAN, returned by [`validate_partial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Partial {
+    /// Nothing is wrong so far, but more characters are needed.
+    Incomplete {
+        /// The country, once its code has been typed.
+        country: Option<Country>,
+        /// How many characters are still missing, once the country is known.
+        remaining: Option<usize>,
+    },
+    /// The IBAN is complete and valid.
+    Valid(Iban),
+    /// Typing more characters can't make this a valid IBAN.
+    Invalid(ValidationError),
+}
+
+/// Checks the start of an IBAN, telling apart input that isn't finished from input that is wrong.
+///
+/// Whitespace is ignored like with [`validate`](crate::validate). The characters typed so far
+/// are checked against the country code and the format of its BBAN, the checks that need the
+/// whole IBAN are done once it has the length of its country.
+pub fn validate_partial<I: AsRef<str>>(input: I) -> Partial {
+    match check_partial(input.as_ref()) {
+        Ok(partial) => partial,
+        Err(error) => Partial::Invalid(error),
+    }
+}
+
+fn check_partial(input: &str) -> Result<Partial, ValidationError> {
+    let (mut bytes, length) = compact(input)?;
+
+    // Whole `str`s were copied, so this is valid UTF-8.
+    let input = core::str::from_utf8(&bytes[..length]).unwrap_or_default();
+    if let Some(error) = invalid_chars(input).next() {
+        return Err(error);
+    }
+
+    if input.len() < 2 {
+        // Any letter some country code starts with can be completed.
+        let completable = input.bytes().all(|first| {
+            (b'A'..=b'Z').any(|second| {
+                let code = [first, second];
+                let code = core::str::from_utf8(&code).unwrap_or_default();
+                Country::from_str(code).is_ok()
+            })
+        });
+        if !completable {
+            return Err(ValidationError::InvalidCountryCode);
+        }
+        return Ok(Partial::Incomplete {
+            country: None,
+            remaining: None,
+        });
+    }
+
+    let country = detect_country(input)?;
+    check_digits_numeric(input)?;
+    if input.len() > country.length() {
+        check_length(country, input)?;
+    }
+    let bban = input.as_bytes().get(4..).unwrap_or_default();
+    if let Some((position, component)) = country.format_errors(bban).next() {
+        return Err(ValidationError::InvalidFormat {
+            country,
+            position: 4 + position,
+            component,
+        });
+    }
+    if input.len() < country.length() {
+        return Ok(Partial::Incomplete {
+            country: Some(country),
+            remaining: Some(country.length() - input.len()),
+        });
+    }
+
+    check_country(country, input)?;
+    check_checksum(country, input)?;
+    bytes[..length].make_ascii_uppercase();
+    Ok(Partial::Valid(Iban {
+        country,
+        bytes,
+        length,
+    }))
+}
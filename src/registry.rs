@@ -0,0 +1,72 @@
+//! Looking up banks by the bank code in their IBANs.
+//!
+//! Lookups go through the [`BankRegistry`] trait. With the `std` feature a [`Registry`] can
+//! be loaded from the bank directories published by national banks.
+
+use crate::bban::NationalCheck;
+use crate::{Bic, Country};
+
+mod check_methods;
+#[cfg(feature = "std")]
+mod files;
+#[cfg(feature = "std")]
+pub use self::files::{ParseError, Registry};
+
+/// A bank and its bank code, returned by [`BankRegistry::lookup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Bank<'a> {
+    /// The country of the bank.
+    pub country: Country,
+    /// The bank code, as returned by [`Iban::bank_code`](crate::Iban::bank_code). If the
+    /// directory assigns the bank a range of bank codes, the first one of the range.
+    pub bank_code: &'a str,
+    /// The last bank code of the range starting at `bank_code`, otherwise the same as
+    /// `bank_code`.
+    pub last_bank_code: &'a str,
+    /// The full name of the bank.
+    pub name: &'a str,
+    /// A shorter name, usually including the location.
+    pub short_name: &'a str,
+    /// The primary BIC of the bank.
+    pub bic: Option<Bic>,
+    /// The check digit method of the account numbers, for German banks.
+    pub check_method: Option<&'a str>,
+}
+
+impl Bank<'_> {
+    /// Verifies the check digit of an account number of this bank.
+    ///
+    /// German banks use one of the check digit methods of the Bundesbank, which is only
+    /// known when the registry was loaded from its bank directory. Methods that aren't
+    /// known return [`NationalCheck::NotVerifiable`].
+    pub fn verify_account_number(&self, account_number: &str) -> NationalCheck {
+        match (self.country, self.check_method) {
+            (Country::Germany, Some(method)) => {
+                check_methods::verify(method, self.bank_code, account_number)
+            }
+            (Country::Germany, None) => NationalCheck::NotVerifiable,
+            _ => NationalCheck::NotApplicable,
+        }
+    }
+}
+
+/// A source of bank data, like a registry loaded from a bank directory.
+pub trait BankRegistry {
+    /// Finds the bank with the given bank code.
+    fn lookup<'a>(&'a self, country: Country, bank_code: &'a str) -> Option<Bank<'a>>;
+
+    /// Returns true if every bank of the country is listed, so bank codes that can't be
+    /// found aren't assigned to any bank. Used by [`validate_with_registry`](crate::validate_with_registry).
+    fn is_complete(&self, country: Country) -> bool;
+}
+
+impl<R: BankRegistry + ?Sized> BankRegistry for &R {
+    fn lookup<'a>(&'a self, country: Country, bank_code: &'a str) -> Option<Bank<'a>> {
+        (**self).lookup(country, bank_code)
+    }
+
+    fn is_complete(&self, country: Country) -> bool {
+        (**self).is_complete(country)
+    }
+}
@@ -0,0 +1,752 @@
+//! Check digit methods (Prüfzifferverfahren) of German account numbers.
+//!
+//! The Bundesbank assigns one of its methods to every bank code. Unknown methods are
+//! reported as [`NationalCheck::NotVerifiable`].
+
+use crate::bban::NationalCheck;
+
+// The digits of the account number, position 1 is on the left.
+type Digits = [u32; 10];
+
+// What happens when the sum divided by 11 has a remainder of 1.
+#[derive(Clone, Copy)]
+enum RemainderOne {
+    // The account number can't be used.
+    Invalid,
+    // The check digit is this digit.
+    Digit(u32),
+}
+
+// A check digit computed from the weighted digits of the account number.
+#[derive(Clone, Copy)]
+struct Rule {
+    modulus: u32,
+    // Applied from the right, repeating if there are more digits.
+    weights: &'static [u32],
+    // Whether the digit sums of the products are added up, instead of the products.
+    digit_sums: bool,
+    remainder_one: RemainderOne,
+    // The first and last weighted position, and the position of the check digit.
+    first: usize,
+    last: usize,
+    check: usize,
+}
+
+static STANDARD: [u32; 9] = [2, 3, 4, 5, 6, 7, 8, 9, 10];
+static POWERS: [u32; 9] = [2, 4, 8, 5, 10, 9, 7, 3, 6];
+static ALTERNATING: [u32; 2] = [2, 1];
+
+// The rows of method 29, used from the right in turn.
+static TRANSFORMATIONS: [[u32; 10]; 4] = [
+    [0, 1, 5, 9, 3, 7, 4, 8, 2, 6],
+    [0, 1, 7, 6, 9, 8, 3, 2, 5, 4],
+    [0, 1, 8, 4, 6, 2, 9, 5, 7, 3],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+];
+
+// The constants put in front of the account numbers of method C6, by their first digit.
+static C6_CONSTANTS: [u64; 10] = [
+    4_451_970, 4_451_981, 4_451_992, 4_451_993, 4_344_992, 4_344_990, 4_344_991, 5_499_570,
+    4_451_994, 5_499_579,
+];
+
+// The weights of the ESER account numbers of methods 52 and 53.
+static ESER: [u32; 12] = [2, 4, 8, 5, 10, 9, 7, 3, 6, 1, 2, 4];
+
+impl Rule {
+    // Weights the positions 1 to 9, the check digit is the last one.
+    fn new(modulus: u32, weights: &'static [u32]) -> Rule {
+        Rule {
+            modulus,
+            weights,
+            digit_sums: false,
+            remainder_one: RemainderOne::Digit(0),
+            first: 1,
+            last: 9,
+            check: 10,
+        }
+    }
+
+    // Weights the positions `first` to `last`, the check digit follows them.
+    fn positions(self, first: usize, last: usize) -> Rule {
+        Rule {
+            first,
+            last,
+            check: last + 1,
+            ..self
+        }
+    }
+
+    fn check_at(self, check: usize) -> Rule {
+        Rule { check, ..self }
+    }
+
+    fn digit_sums(self) -> Rule {
+        Rule {
+            digit_sums: true,
+            ..self
+        }
+    }
+
+    fn remainder_one(self, remainder_one: RemainderOne) -> Rule {
+        Rule {
+            remainder_one,
+            ..self
+        }
+    }
+
+    fn sum(&self, digits: &Digits) -> u32 {
+        digits[self.first - 1..self.last]
+            .iter()
+            .rev()
+            .zip(self.weights.iter().cycle())
+            .map(|(digit, weight)| {
+                let product = digit * weight;
+                if self.digit_sums {
+                    product / 10 + product % 10
+                } else {
+                    product
+                }
+            })
+            .sum()
+    }
+
+    fn check_digit(&self, digits: &Digits) -> Option<u32> {
+        match (self.modulus, self.sum(digits) % self.modulus) {
+            (_, 0) => Some(0),
+            (11, 1) => match self.remainder_one {
+                RemainderOne::Invalid => None,
+                RemainderOne::Digit(digit) => Some(digit),
+            },
+            (modulus, remainder) => Some(modulus - remainder),
+        }
+    }
+
+    fn valid(&self, digits: &Digits) -> bool {
+        self.check_digit(digits) == Some(digits[self.check - 1])
+    }
+}
+
+// The methods that only differ in their weights and positions.
+fn rule(method: &str) -> Option<Rule> {
+    use RemainderOne::*;
+
+    let luhn = Rule::new(10, &ALTERNATING).digit_sums();
+    let mod10 = |weights| Rule::new(10, weights);
+    let mod11 = |weights| Rule::new(11, weights);
+    let rule = match method {
+        "00" | "08" => luhn,
+        "01" => mod10(&[3, 7, 1]),
+        "02" => mod11(&STANDARD[..8]).remainder_one(Invalid),
+        "03" => mod10(&ALTERNATING),
+        "04" => mod11(&STANDARD[..6]).remainder_one(Invalid),
+        "05" => mod10(&[7, 3, 1]),
+        "06" => mod11(&STANDARD[..6]),
+        "07" => mod11(&STANDARD).remainder_one(Invalid),
+        "10" => mod11(&STANDARD),
+        "11" => mod11(&STANDARD).remainder_one(Digit(9)),
+        "14" => mod11(&STANDARD[..6]).positions(4, 9).remainder_one(Invalid),
+        "15" => mod11(&STANDARD[..4]).positions(6, 9),
+        "18" => mod10(&[3, 9, 7, 1]),
+        "19" => mod11(&[2, 3, 4, 5, 6, 7, 8, 9, 1]),
+        "20" => mod11(&[2, 3, 4, 5, 6, 7, 8, 9, 3]),
+        "28" => mod11(&STANDARD[..7]).positions(1, 7),
+        "30" => mod10(&[2, 1, 2, 1, 0, 0, 0, 0, 2]),
+        "32" => mod11(&STANDARD[..6]).positions(4, 9),
+        "33" => mod11(&STANDARD[..5]).positions(5, 9),
+        "34" => mod11(&POWERS[..7]).positions(1, 7),
+        "36" => mod11(&POWERS[..4]).positions(6, 9),
+        "37" | "44" | "A0" => mod11(&POWERS[..5]).positions(5, 9),
+        "38" => mod11(&POWERS[..6]).positions(4, 9),
+        "39" => mod11(&POWERS[..7]).positions(3, 9),
+        "40" => mod11(&POWERS),
+        "42" => mod11(&STANDARD[..8]).positions(2, 9),
+        "43" => mod10(&[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+        "46" => mod11(&STANDARD[..5]).positions(3, 7),
+        "47" => mod11(&STANDARD[..5]).positions(4, 8),
+        "48" => mod11(&STANDARD[..6]).positions(3, 8),
+        "55" => mod11(&[2, 3, 4, 5, 6, 7, 8, 7, 8]),
+        "58" => mod11(&STANDARD[..5]).positions(5, 9).remainder_one(Invalid),
+        "60" => luhn.positions(3, 9),
+        "62" => luhn.positions(3, 7),
+        "64" => mod11(&POWERS[..6]).positions(1, 6),
+        "67" => luhn.positions(1, 7),
+        "71" => mod11(&[1, 2, 3, 4, 5, 6])
+            .positions(2, 7)
+            .check_at(10)
+            .remainder_one(Digit(1)),
+        "72" => luhn.positions(4, 9),
+        "92" => mod10(&[3, 7, 1]).positions(4, 9),
+        "94" => mod10(&[1, 2]).digit_sums(),
+        _ => return None,
+    };
+    Some(rule)
+}
+
+// Verifies a German account number with the check digit method of its bank.
+pub(crate) fn verify(method: &str, bank_code: &str, account_number: &str) -> NationalCheck {
+    let mut digits = [0; 10];
+    if account_number.len() != digits.len() {
+        return NationalCheck::Failed;
+    }
+    for (digit, ch) in digits.iter_mut().zip(account_number.chars()) {
+        match ch.to_digit(10) {
+            Some(value) => *digit = value,
+            None => return NationalCheck::Failed,
+        }
+    }
+    check(method, bank_code, &digits)
+}
+
+fn check(method: &str, bank_code: &str, d: &Digits) -> NationalCheck {
+    use NationalCheck::{NotApplicable, NotVerifiable};
+    use RemainderOne::Invalid;
+
+    // Methods that fall back to other methods.
+    let passes = |method| check(method, bank_code, d) == NationalCheck::Verified;
+    let value = d
+        .iter()
+        .fold(0, |value, &digit| value * 10 + u64::from(digit));
+    // The number of digits without the leading zeros.
+    let length = 10 - d.iter().take_while(|&&digit| digit == 0).count();
+
+    let luhn = Rule::new(10, &ALTERNATING).digit_sums();
+    let mod11 = |weights| Rule::new(11, weights);
+    let mod7 = |weights| Rule::new(7, weights);
+
+    let valid = match method {
+        // No check digit.
+        "09" => return NotApplicable,
+        "08" if value < 60000 => return NotApplicable,
+        "13" => {
+            let rule = luhn.positions(2, 7);
+            // Retried without the sub-account number of positions 9 and 10.
+            rule.valid(d) || rule.valid(&shift(d, 2))
+        }
+        "16" => remainder_one_repeats(mod11(&STANDARD[..6]), d),
+        "17" => {
+            let remainder = (luhn.positions(2, 7).sum(d) + 10) % 11;
+            (10 - remainder) % 10 == d[7]
+        }
+        "21" => {
+            let mut sum = luhn.sum(d);
+            while sum > 9 {
+                sum = sum / 10 + sum % 10;
+            }
+            (10 - sum) % 10 == d[9]
+        }
+        "22" => {
+            // Only the last digit of each product is added up.
+            let sum: u32 = d[..9]
+                .iter()
+                .rev()
+                .zip([3, 1].iter().cycle())
+                .map(|(digit, weight)| digit * weight % 10)
+                .sum();
+            mod10_check_digit(sum) == d[9]
+        }
+        "23" => remainder_one_repeats(mod11(&STANDARD[..6]).positions(1, 6), d),
+        "24" => method_24(d),
+        "25" => {
+            let rule = mod11(&STANDARD[..8]).positions(2, 9);
+            match rule.sum(d) % 11 {
+                1 => d[9] == 0 && matches!(d[1], 8 | 9),
+                _ => rule.valid(d),
+            }
+        }
+        "26" => {
+            let rule = mod11(&STANDARD[..6]).positions(1, 7);
+            if d[0] == 0 && d[1] == 0 {
+                rule.valid(&shift(d, 2))
+            } else {
+                rule.valid(d)
+            }
+        }
+        "27" if d[0] == 0 => passes("00"),
+        "27" | "29" => transformed(d),
+        "31" => mod11(&[9, 8, 7, 6, 5, 4, 3, 2, 1]).sum(d) % 11 == d[9],
+        "35" => match mod11(&STANDARD).sum(d) % 11 {
+            10 => d[8] == d[9],
+            remainder => remainder == d[9],
+        },
+        "41" => {
+            let mut digits = *d;
+            if d[3] == 9 {
+                digits[..3].fill(0);
+            }
+            luhn.valid(&digits)
+        }
+        "45" if d[0] == 0 || d[4] == 1 => return NotApplicable,
+        "45" => passes("00"),
+        "49" => passes("00") || passes("01"),
+        "51" | "73" | "80" | "81" | "84" | "87" | "A8" if d[2] == 9 => ledger_account(d),
+        "51" => {
+            passes("32")
+                || passes("33")
+                || luhn.positions(4, 9).valid(d)
+                || mod7(&STANDARD[..6]).positions(4, 9).valid(d)
+        }
+        "50" => {
+            // Retried with the sub-account number 000 that may be left out.
+            let rule = mod11(&STANDARD[..6]).positions(1, 6).remainder_one(Invalid);
+            rule.valid(d) || (d[..3] == [0; 3] && rule.valid(&shift(d, 3)))
+        }
+        "52" if length == 8 => return eser(bank_code, d[2], None, d[3], &d[4..]),
+        "52" if length == 10 && d[0] == 9 => passes("20"),
+        "52" => return NotVerifiable,
+        "53" if length == 9 => return eser(bank_code, d[1], Some(d[2]), d[3], &d[4..]),
+        "53" => return NotVerifiable,
+        "54" => {
+            let remainder = mod11(&STANDARD[..6]).positions(3, 9).sum(d) % 11;
+            d[0] == 4 && d[1] == 9 && remainder > 1 && 11 - remainder == d[9]
+        }
+        "57" => match d[0] * 10 + d[1] {
+            0 => false,
+            // Positions 3 and 4 have a month, positions 7 to 9 are below 500.
+            1..=31 => {
+                let month = d[2] * 10 + d[3];
+                let serial = d[6] * 100 + d[7] * 10 + d[8];
+                if (1..=12).contains(&month) && serial < 500 || value == 185_125_434 {
+                    return NotApplicable;
+                }
+                false
+            }
+            40 | 50 | 91 | 99 => return NotApplicable,
+            51 | 55 | 61 | 64..=66 | 70 | 73..=82 | 88 | 94 | 95 => {
+                if d[..6] == [7; 6] || d[..6] == [8; 6] {
+                    return NotApplicable;
+                }
+                passes("94")
+            }
+            // The check digit is at position 3.
+            _ => {
+                let digits = [d[0], d[1], d[3], d[4], d[5], d[6], d[7], d[8], d[9], d[2]];
+                check("94", bank_code, &digits) == NationalCheck::Verified
+            }
+        },
+        "56" => match (mod11(&STANDARD[..6]).sum(d) % 11, d[0]) {
+            (0, 9) => d[9] == 8,
+            (1, 9) => d[9] == 7,
+            (0, _) | (1, _) => false,
+            (remainder, _) => 11 - remainder == d[9],
+        },
+        "59" if length < 9 => return NotApplicable,
+        "59" => passes("00"),
+        "61" | "65" => {
+            // Positions 9 and 10 are weighted too if position 9 has this digit.
+            let (marker, weights): (u32, &'static [u32]) = match method {
+                "61" => (8, &[2, 1]),
+                _ => (9, &[1, 2]),
+            };
+            let mut sum = luhn.positions(1, 7).sum(d);
+            if d[8] == marker {
+                sum += Rule::new(10, weights).digit_sums().positions(9, 10).sum(d);
+            }
+            mod10_check_digit(sum) == d[7]
+        }
+        "63" if d[0] != 0 => false,
+        "63" if d[1] == 0 && d[2] == 0 => luhn.positions(2, 7).valid(&shift(d, 2)),
+        "63" => luhn.positions(2, 7).valid(d),
+        "66" if d[1] == 9 => return NotApplicable,
+        "66" => {
+            let sum = mod11(&[2, 3, 4, 5, 6, 0, 0, 7]).positions(2, 9).sum(d);
+            let check_digit = match sum % 11 {
+                0 => 1,
+                1 => 0,
+                remainder => 11 - remainder,
+            };
+            check_digit == d[9]
+        }
+        "68" if length == 10 => d[3] == 9 && luhn.positions(4, 9).valid(d),
+        "68" if (400_000_000..500_000_000).contains(&value) => return NotApplicable,
+        "68" => {
+            // Positions 3 and 4 may be left out.
+            let mut digits = *d;
+            digits[2..4].fill(0);
+            luhn.valid(d) || luhn.valid(&digits)
+        }
+        "69" if d[0] == 9 && d[1] == 3 => return NotApplicable,
+        "69" if d[0] == 9 && d[1] == 7 => transformed(d),
+        "69" => passes("28") || transformed(d),
+        "70" if d[3] == 5 || (d[3] == 6 && d[4] == 9) => {
+            mod11(&STANDARD[..6]).positions(4, 9).valid(d)
+        }
+        "70" => passes("06"),
+        "73" => luhn.positions(4, 9).valid(d) || passes("80"),
+        "74" => {
+            // Six digit account numbers may also round up to the next half decade.
+            let half_decade = (5 - luhn.sum(d) % 5) % 5;
+            passes("00") || (length == 6 && half_decade == d[9])
+        }
+        "75" => match length {
+            6 | 7 => luhn.positions(5, 9).valid(d),
+            9 if d[1] == 9 => luhn.positions(3, 7).valid(d),
+            9 => luhn.positions(2, 6).valid(d),
+            _ => false,
+        },
+        "76" => {
+            // Position 1 is the account type, the check digit is the remainder. Retried
+            // without the sub-account number of positions 9 and 10.
+            let valid = |d: &Digits| {
+                matches!(d[0], 0 | 4 | 6..=9)
+                    && mod11(&STANDARD[..6]).positions(2, 7).sum(d) % 11 == d[7]
+            };
+            valid(d) || valid(&shift(d, 2))
+        }
+        "77" => {
+            let zero = |weights| mod11(weights).positions(6, 10).sum(d) % 11 == 0;
+            zero(&[1, 2, 3, 4, 5]) || zero(&[5, 4, 3, 4, 5])
+        }
+        "78" if length == 8 => return NotApplicable,
+        "78" => passes("00"),
+        "79" => match d[0] {
+            0 => false,
+            3..=8 => luhn.valid(d),
+            _ => luhn.positions(1, 8).valid(d),
+        },
+        "80" => {
+            luhn.positions(5, 9).valid(d)
+                || mod7(&ALTERNATING).digit_sums().positions(5, 9).valid(d)
+        }
+        "81" => passes("32"),
+        "82" if d[2] == 9 && d[3] == 9 => passes("10"),
+        "82" => passes("33"),
+        "83" if d[2] == 9 && d[3] == 9 => mod11(&STANDARD[..7]).positions(3, 9).valid(d),
+        "85" if d[2] == 9 && d[3] == 9 => mod11(&STANDARD[..7])
+            .positions(3, 9)
+            .remainder_one(Invalid)
+            .valid(d),
+        "83" | "85" => {
+            passes("32") || passes("33") || mod7(&STANDARD[..6]).positions(4, 9).valid(d)
+        }
+        "84" => {
+            passes("33")
+                || mod7(&STANDARD[..5]).positions(5, 9).valid(d)
+                || Rule::new(10, &ALTERNATING).positions(5, 9).valid(d)
+        }
+        "87" => method_87(d) || passes("33") || mod7(&STANDARD[..5]).positions(5, 9).valid(d),
+        "86" | "88" | "90" if d[2] == 9 => mod11(&STANDARD[..7]).positions(3, 9).valid(d),
+        "86" => luhn.positions(4, 9).valid(d) || passes("32"),
+        "88" => passes("32"),
+        "89" if length == 8 || length == 9 => passes("10"),
+        "89" if length == 7 => mod11(&STANDARD[..6]).digit_sums().positions(4, 9).valid(d),
+        "89" => return NotApplicable,
+        "90" => {
+            passes("32")
+                || passes("33")
+                || mod7(&STANDARD[..5]).positions(5, 9).valid(d)
+                || Rule::new(9, &STANDARD[..5]).positions(5, 9).valid(d)
+                || Rule::new(10, &ALTERNATING).positions(5, 9).valid(d)
+                || mod7(&ALTERNATING).positions(4, 9).valid(d)
+        }
+        "91" => {
+            // The check digit is at position 7, positions 8 to 10 are only weighted once.
+            let weights: [&'static [u32]; 3] = [&STANDARD[..6], &[7, 6, 5, 4, 3, 2], &POWERS[..6]];
+            weights
+                .iter()
+                .any(|weights| mod11(weights).positions(1, 6).valid(d))
+                || mod11(&[2, 3, 4, 0, 5, 6, 7, 8, 9, 10])
+                    .positions(1, 10)
+                    .check_at(7)
+                    .valid(d)
+        }
+        "93" => {
+            // The check digit is at position 6, or at position 10 after four zeros.
+            let (first, last) = if d[..4] == [0; 4] { (5, 9) } else { (1, 5) };
+            mod11(&STANDARD[..5]).positions(first, last).valid(d)
+                || mod7(&STANDARD[..5]).positions(first, last).valid(d)
+        }
+        "95" if [
+            1..=1_999_999,
+            9_000_000..=25_999_999,
+            396_000_000..=499_999_999,
+            700_000_000..=799_999_999,
+            910_000_000..=989_999_999,
+        ]
+        .iter()
+        .any(|range| range.contains(&value)) =>
+        {
+            return NotApplicable
+        }
+        "95" => passes("06"),
+        "96" if passes("19") || passes("00") => true,
+        "96" if (1_300_000..=99_399_999).contains(&value) => return NotApplicable,
+        "96" => false,
+        "97" => (value / 10 % 11 % 10) as u32 == d[9],
+        "98" => Rule::new(10, &[3, 1, 7]).positions(3, 9).valid(d) || passes("32"),
+        "99" if (396_000_000..=499_999_999).contains(&value) => return NotApplicable,
+        "99" => passes("06"),
+        "A0" if value < 1000 => return NotApplicable,
+        "A1" => (length == 8 || length == 10) && luhn.positions(3, 9).valid(d),
+        "A2" => passes("00") || passes("04"),
+        "A3" => passes("00") || passes("10"),
+        "A4" if d[2] == 9 && d[3] == 9 => passes("33") || passes("93"),
+        "A4" => passes("32") || mod7(&STANDARD[..6]).positions(4, 9).valid(d) || passes("93"),
+        "A5" => passes("00") || (d[0] != 9 && passes("10")),
+        "A6" if d[1] == 8 => passes("00"),
+        "A6" => passes("01"),
+        "A7" => passes("00") || passes("03"),
+        "A8" => passes("32") || luhn.positions(4, 9).valid(d),
+        "A9" => passes("01") || passes("06"),
+        "B0" if length < 10 || d[0] == 8 => false,
+        "B0" if matches!(d[7], 1 | 2 | 3 | 6) => return NotApplicable,
+        "B0" => passes("06"),
+        "B1" => passes("05") || passes("01") || passes("00"),
+        "B2" if d[0] < 8 => passes("02"),
+        "B2" => passes("00"),
+        "B3" if d[0] == 9 => passes("06"),
+        "B3" => passes("32"),
+        "B4" if d[0] == 9 => passes("00"),
+        "B4" => passes("02"),
+        "B5" => passes("05") || (d[0] < 8 && passes("00")),
+        "B6" if d[0] != 0 || (2691..=2699).contains(&(value / 100_000)) => passes("20"),
+        "B6" => return check("53", bank_code, d),
+        "B7" if (1_000_000..=5_999_999).contains(&value)
+            || (700_000_000..=899_999_999).contains(&value) =>
+        {
+            passes("01")
+        }
+        "B7" => return NotApplicable,
+        "B8" if passes("20") || passes("29") => true,
+        "B8" if (5_100_000_000..=5_999_999_999).contains(&value)
+            || (9_010_000_000..=9_109_999_999).contains(&value) =>
+        {
+            return NotApplicable
+        }
+        "B8" => false,
+        "B9" if length == 8 => {
+            let sum: u32 = d[2..9]
+                .iter()
+                .rev()
+                .zip([1, 3, 2].iter().cycle())
+                .map(|(digit, weight)| (digit * weight + weight) % 11)
+                .sum();
+            sum % 10 == d[9] || (sum + 5) % 10 == d[9]
+        }
+        "B9" if length == 7 => {
+            let remainder = mod11(&[1, 2, 3, 4, 5, 6]).positions(4, 9).sum(d) % 11;
+            remainder == d[9] || (remainder + 5) % 10 == d[9]
+        }
+        "B9" => false,
+        "C0" if length == 8 => passes("52") || passes("20"),
+        "C0" => passes("20"),
+        "C1" if d[0] == 5 => {
+            let remainder = (Rule::new(10, &[1, 2]).digit_sums().sum(d) + 10) % 11;
+            (10 - remainder) % 10 == d[9]
+        }
+        "C1" => passes("17"),
+        "C2" => passes("22") || passes("00"),
+        "C3" if d[0] == 9 => passes("58"),
+        "C3" => passes("00"),
+        "C4" if d[0] == 9 => passes("58"),
+        "C4" => passes("15"),
+        "C5" if (length == 6 || length == 9) && (1..=8).contains(&d[10 - length]) => passes("75"),
+        "C5" if length == 8 && (3..=5).contains(&d[2]) => return NotApplicable,
+        "C5" if length == 10 => match (d[0], d[1]) {
+            (1 | 4 | 5 | 6 | 9, _) => passes("29"),
+            (3, _) => passes("00"),
+            (7, 0) | (8, 5) => return NotApplicable,
+            _ => false,
+        },
+        "C5" => false,
+        "C6" => prefixed(C6_CONSTANTS[d[0] as usize], &d[1..]),
+        "C7" => passes("63") || passes("06"),
+        "C8" => passes("00") || passes("04") || passes("07"),
+        "C9" => passes("00") || passes("07"),
+        "D0" if d[0] == 5 && d[1] == 7 => return NotApplicable,
+        "D0" => passes("20"),
+        "D1" if matches!(d[0], 0 | 3 | 9) => false,
+        "D1" => prefixed(428_259, d),
+        "D2" => passes("95") || passes("00") || passes("68"),
+        "D3" => passes("00") || passes("27"),
+        "D4" if d[0] == 0 => false,
+        "D4" => prefixed(428_259, d),
+        "D5" if d[2] == 9 && d[3] == 9 => mod11(&STANDARD[..7]).positions(3, 9).valid(d),
+        "D5" => {
+            passes("32")
+                || mod7(&STANDARD[..6]).positions(4, 9).valid(d)
+                || Rule::new(10, &STANDARD[..6]).positions(4, 9).valid(d)
+        }
+        "D6" => passes("07") || passes("03") || passes("00"),
+        "D7" => luhn.sum(d) % 10 == d[9],
+        "D8" if length == 10 => passes("00"),
+        "D8" if length == 8 => return NotApplicable,
+        "D8" => false,
+        "D9" => passes("00") || passes("10") || passes("18"),
+        "E0" => mod10_check_digit(luhn.sum(d) + 7) == d[9],
+        "E1" => {
+            // The digits are weighted by their ASCII codes.
+            let sum: u32 = d[..9]
+                .iter()
+                .rev()
+                .zip([1, 2, 3, 4, 5, 6, 11, 10, 9].iter())
+                .map(|(digit, weight)| (digit + 48) * weight)
+                .sum();
+            sum % 11 == d[9]
+        }
+        "E2" if d[0] > 5 => false,
+        "E2" => prefixed(4_383_200 + u64::from(d[0]), &d[1..]),
+        "E3" => passes("00") || passes("21"),
+        "E4" => passes("02") || passes("00"),
+        _ => match rule(method) {
+            Some(rule) => rule.valid(d),
+            None => return NotVerifiable,
+        },
+    };
+    if valid {
+        NationalCheck::Verified
+    } else {
+        NationalCheck::Failed
+    }
+}
+
+fn mod10_check_digit(sum: u32) -> u32 {
+    (10 - sum % 10) % 10
+}
+
+// Moves the digits to the left, filling up with zeros.
+fn shift(d: &Digits, places: usize) -> Digits {
+    let mut digits = [0; 10];
+    digits[..10 - places].copy_from_slice(&d[places..]);
+    digits
+}
+
+// Modulus 11, where a remainder of 1 is valid if the check digit repeats the digit
+// before it.
+fn remainder_one_repeats(rule: Rule, d: &Digits) -> bool {
+    match rule.sum(d) % 11 {
+        1 => d[rule.check - 2] == d[rule.check - 1],
+        _ => rule.valid(d),
+    }
+}
+
+// Ledger accounts (Sachkonten) of method 51, which have a 9 at position 3.
+fn ledger_account(d: &Digits) -> bool {
+    Rule::new(11, &STANDARD[..7]).positions(3, 9).valid(d) || Rule::new(11, &STANDARD).valid(d)
+}
+
+// Method 29, the digits are transformed by the rows before they are added up.
+fn transformed(d: &Digits) -> bool {
+    let sum: u32 = d[..9]
+        .iter()
+        .rev()
+        .zip(TRANSFORMATIONS.iter().cycle())
+        .map(|(&digit, row)| row[digit as usize])
+        .sum();
+    mod10_check_digit(sum) == d[9]
+}
+
+// Method 00 over the digits following a constant, the check digit is the last digit.
+fn prefixed(constant: u64, digits: &[u32]) -> bool {
+    let (check_digit, rest) = match digits.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    let mut number = rest
+        .iter()
+        .fold(constant, |number, &digit| number * 10 + u64::from(digit));
+    let mut sum = 0;
+    for weight in ALTERNATING.iter().cycle() {
+        if number == 0 {
+            break;
+        }
+        let product = (number % 10) as u32 * weight;
+        sum += product / 10 + product % 10;
+        number /= 10;
+    }
+    mod10_check_digit(sum) == *check_digit
+}
+
+fn method_24(d: &Digits) -> bool {
+    // A leading 3 to 6 is left out, as are the first 3 digits after a leading 9.
+    let mut digits = *d;
+    match d[0] {
+        3..=6 => digits[0] = 0,
+        9 => digits[..3].fill(0),
+        _ => {}
+    }
+    let start = match digits[..9].iter().position(|&digit| digit != 0) {
+        Some(start) => start,
+        None => return false,
+    };
+    let sum: u32 = digits[start..9]
+        .iter()
+        .zip([1, 2, 3].iter().cycle())
+        .map(|(digit, weight)| (digit * weight + weight) % 11)
+        .sum();
+    sum % 10 == d[9]
+}
+
+// Method A of method 87, from the program code of its description.
+fn method_87(d: &Digits) -> bool {
+    let start = match d[3..9].iter().position(|&digit| digit != 0) {
+        Some(start) => start + 3,
+        None => return false,
+    };
+    // The parity of the position, and which table has the check digit.
+    let mut odd = (start + 1) % 2;
+    let mut table = 0;
+    let mut sum: i32 = 0;
+    for &digit in &d[start..9] {
+        let digit = match digit {
+            0 => 5,
+            1 => 6,
+            5 => 10,
+            6 => 1,
+            digit => digit,
+        } as i32;
+        let same = odd == table;
+        sum += match (same, digit > 5, odd) {
+            (true, true, 0) => 12 - digit,
+            (false, true, 0) => digit - 12,
+            (true, _, _) => digit,
+            (false, _, _) => -digit,
+        };
+        if digit > 5 {
+            table = if same { 1 - odd } else { odd };
+        }
+        odd = 1 - odd;
+    }
+    let check_digit = [[0, 4, 3, 2, 6], [7, 1, 5, 9, 8]][table][sum.rem_euclid(5) as usize];
+    // The check digit may also be off by 5 if position 4 is zero.
+    check_digit == d[9] || (d[3] == 0 && (check_digit + 5) % 10 == d[9])
+}
+
+// Methods 52 and 53 check the ESER account number, made of the last 4 digits of the bank
+// code, the account type, the check digit and the rest of the account number without
+// leading zeros. Method 53 puts a digit of the account number into the bank code.
+fn eser(
+    bank_code: &str,
+    account_type: u32,
+    bank_code_digit: Option<u32>,
+    check_digit: u32,
+    rest: &[u32],
+) -> NationalCheck {
+    if bank_code.len() != 8 || !bank_code.bytes().all(|b| b.is_ascii_digit()) {
+        return NationalCheck::NotVerifiable;
+    }
+    let mut digits = [0; 12];
+    for (digit, byte) in digits.iter_mut().zip(bank_code.bytes().skip(4)) {
+        *digit = u32::from(byte - b'0');
+    }
+    if let Some(digit) = bank_code_digit {
+        digits[2] = digit;
+    }
+    digits[4] = account_type;
+    digits[5] = check_digit;
+    let rest = &rest[rest.iter().take_while(|&&digit| digit == 0).count()..];
+    let length = 6 + rest.len();
+    digits[6..length].copy_from_slice(rest);
+
+    let sum: u32 = digits[..length]
+        .iter()
+        .rev()
+        .zip(ESER.iter())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    if sum % 11 == 10 {
+        NationalCheck::Verified
+    } else {
+        NationalCheck::Failed
+    }
+}
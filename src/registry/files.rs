@@ -0,0 +1,476 @@
+//! Registries loaded from the bank directories published by national banks.
+
+use super::{Bank, BankRegistry};
+use crate::bban::Component;
+use crate::{validate_bic, Bic, Country};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+/// Error returned when a bank directory can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// No line of the file has the columns this format needs.
+    MissingColumns,
+    /// A line doesn't have the layout of this format, or its bank code doesn't fit the country.
+    InvalidLine {
+        /// Line number, starting at 1.
+        line: usize,
+    },
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingColumns => write!(f, "File doesn't have the expected columns."),
+            ParseError::InvalidLine { line } => write!(f, "Line {} has an invalid format.", line),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    country: Country,
+    first: String,
+    last: String,
+    name: String,
+    short_name: String,
+    bic: Option<Bic>,
+    check_method: Option<String>,
+}
+
+// The fields of a line in a bank directory.
+struct Row<'a> {
+    bank_code: &'a str,
+    name: &'a str,
+    short_name: &'a str,
+    bic: &'a str,
+    check_method: &'a str,
+}
+
+impl Entry {
+    fn bank(&self) -> Bank<'_> {
+        Bank {
+            country: self.country,
+            bank_code: &self.first,
+            last_bank_code: &self.last,
+            name: &self.name,
+            short_name: &self.short_name,
+            bic: self.bic,
+            check_method: self.check_method.as_deref(),
+        }
+    }
+}
+
+/// A bank registry loaded at runtime.
+///
+/// The `from_*` functions parse the files national banks publish, [`Registry::extend`]
+/// combines them. Files have to be decoded to a string first, the Bundesbank file is
+/// ISO-8859-1 for example. If a bank code is listed more than once, the first entry wins.
+/// A bank code in overlapping ranges belongs to the range starting closest to it.
+///
+/// The `from_*` functions expect the whole directory, the countries they find banks of
+/// are [complete](BankRegistry::is_complete). Use [`Registry::set_complete`] when only
+/// part of a directory is loaded.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    // Keyed by country code and the first bank code of the entry.
+    entries: BTreeMap<(&'static str, String), Entry>,
+    // The countries a whole national directory was loaded for.
+    complete: Vec<Country>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Parses the bank directory (Bankleitzahlendatei) of the Deutsche Bundesbank.
+    ///
+    /// This is the fixed-width text file, only the main entry of each bank code is used.
+    pub fn from_bundesbank(content: &str) -> Result<Registry, ParseError> {
+        let mut registry = Registry::new();
+        for (index, line) in lines(content) {
+            let chars: Vec<char> = line.chars().collect();
+            if chars.len() < 152 {
+                return Err(ParseError::InvalidLine { line: index + 1 });
+            }
+
+            // Branches have a 2 here, they share the bank code of the main entry.
+            if chars[8] != '1' {
+                continue;
+            }
+
+            let field = |from: usize, to: usize| -> String {
+                chars[from - 1..to].iter().collect::<String>().trim().into()
+            };
+            let row = Row {
+                bank_code: &field(1, 8),
+                name: &field(10, 67),
+                short_name: &field(108, 134),
+                bic: &field(140, 150),
+                check_method: &field(151, 152),
+            };
+            registry.insert(index, Country::Germany, row)?;
+        }
+        registry.complete_loaded(&[Country::Germany]);
+        Ok(registry)
+    }
+
+    /// Parses the bank master data (Bankenstamm) CSV of SIX Interbank Clearing.
+    ///
+    /// Columns are found by their header: `IID` or `BC-Nr`, `Bank/institution name`,
+    /// `Short name`, `BIC` and `Country code`, in English or German. Banks with the
+    /// country code `LI` are listed under Liechtenstein, which uses the same bank codes.
+    pub fn from_six(content: &str) -> Result<Registry, ParseError> {
+        Registry::from_csv(
+            &[Country::Switzerland, Country::Liechenstein],
+            content,
+            &Columns {
+                bank_code: &["IID", "BC-Nr", "BC-Nr.", "BC number", "Bankenclearing-Nr."],
+                name: &[
+                    "Bank/institution name",
+                    "Name of bank/institution",
+                    "Bank-/Institutsname",
+                    "Bankname",
+                ],
+                short_name: &["Short name", "Kurzbezeichnung", "Kurzbez."],
+                bic: &["BIC", "SWIFT", "SWIFT-BIC", "BIC/SWIFT"],
+                country: &["Country code", "Landcode", "Country", "Land"],
+            },
+        )
+    }
+
+    /// Parses the bank directory of the Oesterreichische Nationalbank, exported as CSV.
+    ///
+    /// Columns are found by their header: `Bankleitzahl`, `Bankenname` and `SWIFT-Code`.
+    pub fn from_oenb(content: &str) -> Result<Registry, ParseError> {
+        Registry::from_csv(
+            &[Country::Austria],
+            content,
+            &Columns {
+                bank_code: &["Bankleitzahl", "BLZ"],
+                name: &["Bankenname", "Name"],
+                short_name: &[],
+                bic: &["SWIFT-Code", "SWIFT Code", "BIC"],
+                country: &[],
+            },
+        )
+    }
+
+    /// Parses the list of bank identification codes of the National Bank of Belgium,
+    /// exported from Excel as CSV.
+    ///
+    /// Columns are found by their header: `T_Identification_Number`, `Biccode` and the
+    /// institution name in English, Dutch, French or German, in that order of preference.
+    /// Identification numbers can be ranges like `000-000` or `050-099`.
+    pub fn from_nbb(content: &str) -> Result<Registry, ParseError> {
+        Registry::from_csv(
+            &[Country::Belgium],
+            content,
+            &Columns {
+                bank_code: &["T_Identification_Number", "Identification Number"],
+                name: &[
+                    "T_Institutions_English",
+                    "T_Institutions_Dutch",
+                    "T_Institutions_French",
+                    "T_Institutions_German",
+                ],
+                short_name: &[],
+                bic: &["Biccode", "BIC"],
+                country: &[],
+            },
+        )
+    }
+
+    /// Parses the Dutch BIC list (BIC-lijst) of the Betaalvereniging, exported as CSV.
+    ///
+    /// Columns are found by their header: `Identifier`, `BIC` and `Naam betaalinstelling`.
+    pub fn from_dutch_bic_list(content: &str) -> Result<Registry, ParseError> {
+        Registry::from_csv(
+            &[Country::Netherlands],
+            content,
+            &Columns {
+                bank_code: &["Identifier", "Bank identifier"],
+                name: &["Naam betaalinstelling", "Name payment institution", "Naam"],
+                short_name: &[],
+                bic: &["BIC"],
+                country: &[],
+            },
+        )
+    }
+
+    /// Adds the banks of another registry, keeping existing entries.
+    ///
+    /// Countries complete in either registry are complete in this one.
+    pub fn extend(&mut self, other: Registry) {
+        for (key, entry) in other.entries {
+            self.entries.entry(key).or_insert(entry);
+        }
+        for country in other.complete {
+            self.set_complete(country, true);
+        }
+    }
+
+    /// Sets whether every bank of the country is listed, see [`BankRegistry::is_complete`].
+    pub fn set_complete(&mut self, country: Country, complete: bool) {
+        self.complete.retain(|&other| other != country);
+        if complete {
+            self.complete.push(country);
+        }
+    }
+
+    /// Every bank in the registry, a range of bank codes is returned with its first and
+    /// last code.
+    pub fn banks(&self) -> impl Iterator<Item = Bank<'_>> {
+        self.entries.values().map(Entry::bank)
+    }
+
+    /// Finds the banks with the given BIC, see [`Bic::matches`].
+    pub fn lookup_bic(&self, bic: &Bic) -> impl Iterator<Item = Bank<'_>> {
+        let bic = *bic;
+        self.banks()
+            .filter(move |bank| matches!(bank.bic, Some(other) if bic.matches(&other)))
+    }
+
+    /// Number of entries in the registry.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the registry has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // The first country is used for rows without a country code.
+    fn from_csv(
+        countries: &[Country],
+        content: &str,
+        columns: &Columns,
+    ) -> Result<Registry, ParseError> {
+        let mut lines = lines(content);
+        let (delimiter, header) = lines
+            .by_ref()
+            .find_map(|(_, line)| columns.find(line))
+            .ok_or(ParseError::MissingColumns)?;
+
+        let mut registry = Registry::new();
+        for (index, line) in lines {
+            let fields = split(line, delimiter);
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|column| fields.get(column))
+                    .map_or("", String::as_str)
+            };
+
+            let bank_code = field(Some(header.bank_code));
+            if bank_code.is_empty() {
+                continue;
+            }
+            // Rows without a name are unassigned bank codes.
+            let name = match header
+                .name
+                .iter()
+                .map(|&column| field(Some(column)))
+                .find(|name| !name.is_empty())
+            {
+                Some(name) => name,
+                None => continue,
+            };
+            let short_name = match field(header.short_name) {
+                "" => name,
+                short_name => short_name,
+            };
+            let country = match field(header.country) {
+                "" => countries[0],
+                code => *countries
+                    .iter()
+                    .find(|country| country.code().eq_ignore_ascii_case(code))
+                    .ok_or(ParseError::InvalidLine { line: index + 1 })?,
+            };
+            let row = Row {
+                bank_code,
+                name,
+                short_name,
+                bic: field(header.bic),
+                check_method: "",
+            };
+            registry.insert(index, country, row)?;
+        }
+        registry.complete_loaded(countries);
+        Ok(registry)
+    }
+
+    // Marks the countries of a directory complete, if it had any of their banks.
+    fn complete_loaded(&mut self, countries: &[Country]) {
+        for &country in countries {
+            let loaded = self.entries.keys().any(|(code, _)| *code == country.code());
+            self.set_complete(country, loaded);
+        }
+    }
+
+    fn insert(&mut self, index: usize, country: Country, row: Row) -> Result<(), ParseError> {
+        let (first, last) = match row.bank_code.split_once('-') {
+            Some((first, last)) => (first.trim(), last.trim()),
+            None => (row.bank_code, row.bank_code),
+        };
+        let invalid = ParseError::InvalidLine { line: index + 1 };
+        let first = normalize(country, first).ok_or_else(|| invalid.clone())?;
+        let last = normalize(country, last).ok_or_else(|| invalid.clone())?;
+        if last < first {
+            return Err(invalid);
+        }
+
+        // Directories mark missing BICs in different ways, like "-" or "NAV".
+        let bic = validate_bic(row.bic).ok();
+        let check_method = match row.check_method {
+            "" => None,
+            method => Some(method.to_string()),
+        };
+        self.entries
+            .entry((country.code(), first.clone()))
+            .or_insert(Entry {
+                country,
+                first,
+                last,
+                name: row.name.into(),
+                short_name: row.short_name.into(),
+                bic,
+                check_method,
+            });
+        Ok(())
+    }
+}
+
+impl BankRegistry for Registry {
+    fn lookup<'a>(&'a self, country: Country, bank_code: &'a str) -> Option<Bank<'a>> {
+        // Ranges can overlap, so the closest entry before the bank code might not contain it.
+        let start = (country.code(), String::new());
+        let key = (country.code(), bank_code.to_string());
+        let (_, entry) = self.entries.range(start..=key).rev().find(|(_, entry)| {
+            bank_code.len() == entry.last.len() && bank_code <= entry.last.as_str()
+        })?;
+        Some(entry.bank())
+    }
+
+    fn is_complete(&self, country: Country) -> bool {
+        self.complete.contains(&country)
+    }
+}
+
+struct Columns {
+    bank_code: &'static [&'static str],
+    name: &'static [&'static str],
+    short_name: &'static [&'static str],
+    bic: &'static [&'static str],
+    country: &'static [&'static str],
+}
+
+// Positions of the columns in the header of a CSV file.
+struct Header {
+    bank_code: usize,
+    // All name columns that were found, in order of preference.
+    name: Vec<usize>,
+    short_name: Option<usize>,
+    bic: Option<usize>,
+    country: Option<usize>,
+}
+
+impl Columns {
+    // Returns the delimiter and the columns if this line is the header.
+    fn find(&self, line: &str) -> Option<(char, Header)> {
+        [';', ',', '\t'].iter().find_map(|&delimiter| {
+            let fields = split(line, delimiter);
+            let position = |names: &[&str]| {
+                names.iter().find_map(|name| {
+                    fields
+                        .iter()
+                        .position(|field| field.eq_ignore_ascii_case(name))
+                })
+            };
+            let name: Vec<usize> = self
+                .name
+                .iter()
+                .filter_map(|name| position(&[name]))
+                .collect();
+            if name.is_empty() {
+                return None;
+            }
+            let header = Header {
+                bank_code: position(self.bank_code)?,
+                name,
+                short_name: position(self.short_name),
+                bic: position(self.bic),
+                country: position(self.country),
+            };
+            Some((delimiter, header))
+        })
+    }
+}
+
+// Non-empty lines with their index, without a byte order mark.
+fn lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+// Splits a line of CSV, fields can be quoted and quotes in them doubled.
+fn split(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ch if ch == delimiter && !quoted => {
+                fields.push(field.trim().to_string());
+                field.clear();
+            }
+            ch => field.push(ch),
+        }
+    }
+    fields.push(field.trim().to_string());
+    fields
+}
+
+// Pads numeric bank codes with the zeros spreadsheets drop and checks the format.
+fn normalize(country: Country, bank_code: &str) -> Option<String> {
+    let spec = country.bban_spec();
+    let length = spec.component_length(Component::BankCode);
+    if length == 0 || bank_code.is_empty() || bank_code.len() > length {
+        return None;
+    }
+
+    let mut normalized = if bank_code.bytes().all(|b| b.is_ascii_digit()) {
+        "0".repeat(length - bank_code.len())
+    } else {
+        String::new()
+    };
+    normalized.push_str(&bank_code.to_ascii_uppercase());
+
+    let classes = spec
+        .component(Component::BankCode)
+        .flat_map(|segment| (0..segment.length).map(move |_| segment.class));
+    let valid = normalized.len() == length
+        && normalized
+            .chars()
+            .zip(classes)
+            .all(|(ch, class)| class.matches(ch));
+    if valid {
+        Some(normalized)
+    } else {
+        None
+    }
+}
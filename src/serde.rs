@@ -0,0 +1,88 @@
+//! Serde support, IBANs, BICs and countries are (de)serialized as strings.
+
+use crate::{validate, validate_bic, Bic, Country, Iban};
+use core::fmt;
+use core::str::FromStr;
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+// Serialized in electronic format, deserializing runs `validate` on the string.
+impl Serialize for Iban {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.raw())
+    }
+}
+
+impl<'de> Deserialize<'de> for Iban {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IbanVisitor;
+
+        impl<'de> Visitor<'de> for IbanVisitor {
+            type Value = Iban;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an IBAN")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Iban, E> {
+                validate(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IbanVisitor)
+    }
+}
+
+// Same as `Iban`, deserializing runs `validate_bic`.
+impl Serialize for Bic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BicVisitor;
+
+        impl<'de> Visitor<'de> for BicVisitor {
+            type Value = Bic;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a BIC")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Bic, E> {
+                validate_bic(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(BicVisitor)
+    }
+}
+
+// Serialized as the country code, like `Display` does.
+impl Serialize for Country {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CountryVisitor;
+
+        impl<'de> Visitor<'de> for CountryVisitor {
+            type Value = Country;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a country code supported by IBAN")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Country, E> {
+                Country::from_str(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(CountryVisitor)
+    }
+}
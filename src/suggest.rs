@@ -0,0 +1,68 @@
+//! Suggestions for mistyped IBANs.
+
+use crate::{validate, Iban};
+
+// Characters that are easily mistaken for each other, both by OCR and by people.
+const CONFUSIONS: [(char, char); 4] = [('O', '0'), ('I', '1'), ('S', '5'), ('B', '8')];
+
+const ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Kinds of typos, from the most to the least likely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Edit {
+    Confusion,
+    Transposition,
+    Substitution,
+}
+
+/// Suggests valid IBANs the input could have been before a single typo.
+///
+/// Every candidate is a single substitution, a swap of two adjacent characters,
+/// or a mix-up of similar looking characters like `O` and `0`. The ones that pass
+/// [`validate`] are returned, most likely first. Valid input gets no suggestions.
+pub fn suggest_corrections<I: AsRef<str>>(input: I) -> Vec<Iban> {
+    let input = input.as_ref();
+    if validate(input).is_ok() {
+        return Vec::new();
+    }
+
+    let input: Vec<char> = input.split_whitespace().flat_map(str::chars).collect();
+
+    let mut candidates: Vec<(Edit, Vec<char>)> = Vec::new();
+    for i in 0..input.len() {
+        for &(a, b) in CONFUSIONS.iter() {
+            if input[i] == a || input[i] == b {
+                let mut candidate = input.clone();
+                candidate[i] = if input[i] == a { b } else { a };
+                candidates.push((Edit::Confusion, candidate));
+            }
+        }
+
+        if i + 1 < input.len() && input[i] != input[i + 1] {
+            let mut candidate = input.clone();
+            candidate.swap(i, i + 1);
+            candidates.push((Edit::Transposition, candidate));
+        }
+
+        for ch in ALPHABET.chars().filter(|&ch| ch != input[i]) {
+            let mut candidate = input.clone();
+            candidate[i] = ch;
+            candidates.push((Edit::Substitution, candidate));
+        }
+    }
+
+    // Stable, so candidates of the same kind stay in the order of their position.
+    candidates.sort_by_key(|(edit, _)| *edit);
+
+    let mut suggestions: Vec<Iban> = Vec::new();
+    for (_, candidate) in candidates {
+        let candidate: String = candidate.into_iter().collect();
+        if let Ok(iban) = validate(&candidate) {
+            if !suggestions.iter().any(|s| s.raw() == iban.raw()) {
+                suggestions.push(iban);
+            }
+        }
+    }
+
+    suggestions
+}
@@ -0,0 +1,78 @@
+use schwifty::*;
+
+#[test]
+fn validate_bic_parts() {
+    let bic = validate_bic("DEUTDEFF500").unwrap();
+    assert_eq!(bic.institution_code(), "DEUT");
+    assert_eq!(bic.country_code(), "DE");
+    assert_eq!(bic.country(), Some(Country::Germany));
+    assert_eq!(bic.location_code(), "FF");
+    assert_eq!(bic.branch_code(), Some("500"));
+    assert_eq!(bic.to_string(), "DEUTDEFF500");
+
+    let bic = validate_bic("nwbk gb 2l").unwrap();
+    assert_eq!(bic.as_str(), "NWBKGB2L");
+    assert_eq!(bic.branch_code(), None);
+    assert_eq!(bic, "NWBKGB2L".parse().unwrap());
+
+    // Countries without IBANs have BICs too.
+    let bic = validate_bic("CHASUS33").unwrap();
+    assert_eq!(bic.country_code(), "US");
+    assert_eq!(bic.country(), None);
+}
+
+#[test]
+fn bic_matches() {
+    let bic = |bic| validate_bic(bic).unwrap();
+    assert!(bic("DEUTDEFF").matches(&bic("DEUTDEFF")));
+    assert!(bic("DEUTDEFF").matches(&bic("DEUTDEFFXXX")));
+    assert!(bic("DEUTDEFF").matches(&bic("DEUTDEFF500")));
+    assert!(bic("DEUTDEFFXXX").matches(&bic("DEUTDEFF")));
+    assert!(bic("DEUTDEFF500").matches(&bic("deutdeff500")));
+    assert!(!bic("DEUTDEFFXXX").matches(&bic("DEUTDEFF500")));
+    assert!(!bic("DEUTDEFF500").matches(&bic("DEUTDEFF")));
+    assert!(!bic("DEUTDEFF").matches(&bic("DEUTDEHH")));
+}
+
+#[test]
+fn validate_bic_errors() {
+    assert_eq!(
+        validate_bic("DEUTDEF").unwrap_err(),
+        ValidationError::InvalidBicLength { length: 7 }
+    );
+    assert_eq!(
+        validate_bic("DEUTDEFF5").unwrap_err(),
+        ValidationError::InvalidBicLength { length: 9 }
+    );
+    assert_eq!(
+        validate_bic("DEUTDEFF500123").unwrap_err(),
+        ValidationError::InvalidBicLength { length: 14 }
+    );
+    assert_eq!(
+        validate_bic("DEUT-EFF").unwrap_err(),
+        ValidationError::InvalidChar { position: 4 }
+    );
+    assert_eq!(
+        validate_bic("DEUTDÉF").unwrap_err(),
+        ValidationError::InvalidChar { position: 5 }
+    );
+    assert_eq!(
+        validate_bic("DEUTD1FF").unwrap_err(),
+        ValidationError::InvalidBicFormat { position: 5 }
+    );
+}
+
+#[test]
+fn bic_plausible_for_iban() {
+    let iban = validate("DE89 3704 0044 0532 0130 00").unwrap();
+    assert!(validate_bic("COBADEFFXXX").unwrap().is_plausible_for(&iban));
+    assert!(!validate_bic("BNPAFRPP").unwrap().is_plausible_for(&iban));
+
+    // Jersey banks have British IBANs, French overseas departments French ones.
+    let iban = validate("GB29 NWBK 6016 1331 9268 19").unwrap();
+    assert!(validate_bic("RBOSJESH").unwrap().is_plausible_for(&iban));
+    assert!(!validate_bic("RBOSIESH").unwrap().is_plausible_for(&iban));
+    let iban = validate("FR14 2004 1010 0505 0001 3M02 606").unwrap();
+    assert!(validate_bic("BNPARERX").unwrap().is_plausible_for(&iban));
+    assert!(!validate_bic("BNPAGBRX").unwrap().is_plausible_for(&iban));
+}
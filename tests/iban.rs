@@ -1,3 +1,4 @@
+use schwifty::bban::Component;
 use schwifty::*;
 
 #[test]
@@ -94,8 +95,773 @@ fn validate_iban_empty() {
         validate("F"),
         Err(ValidationError::InvalidCountryCode)
     ));
+    assert_eq!(
+        validate("FR").unwrap_err(),
+        ValidationError::InvalidLength {
+            country: Country::France,
+            expected: 27,
+            actual: 2
+        }
+    );
+}
+
+#[test]
+fn validate_iban_errors() {
+    assert_eq!(
+        validate("GB82 WEST 1234 5698 7654 3210 9876 5432 123").unwrap_err(),
+        ValidationError::TooLong { length: 35 }
+    );
+    assert_eq!(
+        validate("GB82 WEST 1234-5698 7654 32").unwrap_err(),
+        ValidationError::InvalidChar { position: 12 }
+    );
+    assert_eq!(
+        validate("XX82 WEST 1234 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidCountryCode
+    );
+    assert_eq!(
+        validate("GBAK WEST 1234 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidChar { position: 2 }
+    );
+    assert_eq!(
+        validate_ref("GB8KWEST12345698765432").unwrap_err(),
+        ValidationError::InvalidChar { position: 3 }
+    );
+    assert_eq!(
+        validate("GB82 WEST 1234 5698 7654 3").unwrap_err(),
+        ValidationError::InvalidLength {
+            country: Country::UnitedKingdom,
+            expected: 22,
+            actual: 21
+        }
+    );
+    assert_eq!(
+        validate("GB82 WEST 123A 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidFormat {
+            country: Country::UnitedKingdom,
+            position: 11,
+            component: Component::BranchCode
+        }
+    );
+    assert_eq!(
+        validate("BE68 5390 0754 7035").unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country: Country::Belgium,
+            component: Component::NationalCheckDigits
+        }
+    );
+    assert_eq!(
+        validate("GB83 WEST 1234 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidIban {
+            country: Country::UnitedKingdom,
+            expected: 82
+        }
+    );
+}
+
+#[test]
+fn validate_ref_borrowed() {
+    let iban = validate_ref("GB82WEST12345698765432").unwrap();
+    assert_eq!(iban.country, Country::UnitedKingdom);
+    assert_eq!(iban.country_code(), "GB");
+    assert_eq!(iban.check_digits(), "82");
+    assert_eq!(iban.bank_code(), "WEST");
+    assert_eq!(iban.account_number(), "98765432");
+    assert_eq!(iban.raw(), "GB82WEST12345698765432");
+
+    let bytes: &[u8] = b"BE68539007547034";
+    assert_eq!(validate_ref(bytes).unwrap().raw(), "BE68539007547034");
+    validate_ref(&String::from("DE89370400440532013000")).unwrap();
+
+    // Only the electronic format is accepted.
+    assert_eq!(
+        validate_ref("GB82 WEST 1234 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidChar { position: 4 }
+    );
+    assert_eq!(
+        validate_ref(&b"GB82WEST\xff2345698765432"[..]).unwrap_err(),
+        ValidationError::InvalidChar { position: 8 }
+    );
+    assert_eq!(
+        validate_ref(&b"GB-2WEST\xff2345698765432"[..]).unwrap_err(),
+        ValidationError::InvalidChar { position: 2 }
+    );
+    assert_eq!(
+        validate_ref("GB83WEST12345698765432").unwrap_err(),
+        validate("GB83WEST12345698765432").unwrap_err()
+    );
+}
+
+#[test]
+fn iban_value_type() {
+    use std::collections::{BTreeSet, HashMap};
+    use std::convert::TryFrom;
+
+    let iban = validate("GB82 WEST 1234 5698 7654 32").unwrap();
+    let copy = iban;
+    assert_eq!(iban, copy);
+    assert_eq!(iban.bank_code(), "WEST");
+    assert_eq!(iban.account_number(), "98765432");
+    assert_eq!(iban.country_code(), "GB");
+    assert_eq!(format!("{:#}", iban), "GB82WEST12345698765432");
+    assert_eq!(iban.as_ref(), "GB82WEST12345698765432");
+
+    // Whitespace doesn't matter once it is validated.
+    assert_eq!("GB82WEST12345698765432".parse::<Iban>().unwrap(), iban);
+    assert_eq!(Iban::try_from("GB 82 WEST12345698765432").unwrap(), iban);
+    assert_eq!(
+        "GB82WEST12345698765433".parse::<Iban>().unwrap_err(),
+        ValidationError::InvalidIban {
+            country: Country::UnitedKingdom,
+            expected: 55
+        }
+    );
+
+    let mut balances = HashMap::new();
+    balances.insert(iban, 10);
+    assert_eq!(balances[&copy], 10);
+
+    // Lowercase letters are stored in uppercase, so both spellings are the same IBAN.
+    let lower = validate("FR14 2004 1010 0505 0001 3m02 606").unwrap();
+    let upper = validate("FR14 2004 1010 0505 0001 3M02 606").unwrap();
+    assert_eq!(lower, upper);
+    assert_eq!(lower.raw(), "FR1420041010050500013M02606");
+    assert_eq!(lower.to_string(), upper.to_string());
+    balances.insert(lower, 20);
+    assert_eq!(balances[&upper], 20);
+    assert_eq!(balances.len(), 2);
+
+    let sorted: BTreeSet<Iban> = [
+        "GB82WEST12345698765432",
+        "BE68539007547034",
+        "DE89370400440532013000",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    let sorted: Vec<&str> = sorted.iter().map(Iban::raw).collect();
+    assert_eq!(
+        sorted,
+        [
+            "BE68539007547034",
+            "DE89370400440532013000",
+            "GB82WEST12345698765432"
+        ]
+    );
+}
+
+#[test]
+fn iban_print_format() {
+    let iban = validate("GB82WEST12345698765432").unwrap();
+    assert_eq!(iban.to_string(), "GB82 WEST 1234 5698 7654 32");
+    assert_eq!(format!("{:#}", iban), "GB82WEST12345698765432");
+    assert_eq!(format!("{:>#24}", iban), "  GB82WEST12345698765432");
+    assert_eq!(format!("{:<#24}", iban), "GB82WEST12345698765432  ");
+    assert_eq!(format!("{:#>30}", iban), "###GB82 WEST 1234 5698 7654 32");
+    assert_eq!(format!("{:>30}", iban), "   GB82 WEST 1234 5698 7654 32");
+    assert_eq!(format!("{:*^30}", iban), "*GB82 WEST 1234 5698 7654 32**");
+    assert_eq!(format!("{:10}", iban), "GB82 WEST 1234 5698 7654 32");
+    assert_eq!(
+        format!("{:_<30}", iban.grouped("-", 4)),
+        "GB82-WEST-1234-5698-7654-32___"
+    );
+
+    let malta = validate("MT84MALT011000012345MTLCAST001S").unwrap();
+    assert_eq!(malta.to_string(), "MT84 MALT 0110 0001 2345 MTLC AST0 01S");
+    let saint_lucia = validate("LC55HEMM000100010012001200023015").unwrap();
+    assert_eq!(
+        saint_lucia.to_string(),
+        "LC55 HEMM 0001 0001 0012 0012 0002 3015"
+    );
+    let norway = validate("NO9386011117947").unwrap();
+    assert_eq!(norway.to_string(), "NO93 8601 1117 947");
+
+    assert_eq!(
+        iban.grouped("-", 4).to_string(),
+        "GB82-WEST-1234-5698-7654-32"
+    );
+    assert_eq!(
+        iban.grouped(" ", 6).to_string(),
+        "GB82WE ST1234 569876 5432"
+    );
+    assert_eq!(iban.grouped(" ", 0).to_string(), "GB82WEST12345698765432");
+    assert_eq!(iban.grouped(" ", 40).to_string(), "GB82WEST12345698765432");
+}
+
+#[test]
+fn validate_partial_typing() {
+    let iban = "GB82WEST12345698765432";
+    assert_eq!(
+        validate_partial(""),
+        Partial::Incomplete {
+            country: None,
+            remaining: None
+        }
+    );
+    assert_eq!(
+        validate_partial("G"),
+        Partial::Incomplete {
+            country: None,
+            remaining: None
+        }
+    );
+    for typed in 2..iban.len() {
+        assert_eq!(
+            validate_partial(&iban[..typed]),
+            Partial::Incomplete {
+                country: Some(Country::UnitedKingdom),
+                remaining: Some(iban.len() - typed)
+            }
+        );
+    }
+    assert_eq!(
+        validate_partial(iban),
+        Partial::Valid(validate(iban).unwrap())
+    );
+    assert_eq!(
+        validate_partial("GB82 WEST 12"),
+        Partial::Incomplete {
+            country: Some(Country::UnitedKingdom),
+            remaining: Some(12)
+        }
+    );
+
+    // Wrong already, typing more doesn't help.
+    let invalid = |input| match validate_partial(input) {
+        Partial::Invalid(error) => error,
+        partial => panic!("{:?} is {:?}", input, partial),
+    };
+    assert_eq!(invalid("Z"), ValidationError::InvalidCountryCode);
+    assert_eq!(invalid("GX"), ValidationError::InvalidCountryCode);
+    assert_eq!(invalid("G-"), ValidationError::InvalidChar { position: 1 });
+    assert_eq!(
+        invalid("GBXX"),
+        ValidationError::InvalidChar { position: 2 }
+    );
+    assert_eq!(
+        invalid("GB8X"),
+        ValidationError::InvalidChar { position: 3 }
+    );
+    assert_eq!(
+        invalid("GB82WES1"),
+        ValidationError::InvalidFormat {
+            country: Country::UnitedKingdom,
+            position: 7,
+            component: Component::BankCode
+        }
+    );
+    assert_eq!(
+        invalid("GB82WEST123456987654321"),
+        ValidationError::InvalidLength {
+            country: Country::UnitedKingdom,
+            expected: 22,
+            actual: 23
+        }
+    );
+    assert_eq!(
+        invalid("GB82WEST12345698765433"),
+        ValidationError::InvalidIban {
+            country: Country::UnitedKingdom,
+            expected: 55
+        }
+    );
+    assert_eq!(
+        invalid("BE68539007547035"),
+        ValidationError::CountryCheckFailed {
+            country: Country::Belgium,
+            component: Component::NationalCheckDigits
+        }
+    );
+}
+
+#[test]
+fn bban_spec_layout() {
+    use schwifty::bban::CharClass;
+    use std::str::FromStr;
+
+    let spec = Country::UnitedKingdom.bban_spec();
+    assert_eq!(spec.country(), Country::UnitedKingdom);
+    assert_eq!(spec.length(), 18);
+    let layout: Vec<_> = spec
+        .segments()
+        .iter()
+        .map(|s| (s.component, s.class, s.offset, s.length))
+        .collect();
+    assert_eq!(
+        layout,
+        [
+            (Component::BankCode, CharClass::Alpha, 0, 4),
+            (Component::BranchCode, CharClass::Numeric, 4, 6),
+            (Component::AccountNumber, CharClass::Numeric, 10, 8),
+        ]
+    );
+    assert_eq!(spec.component_length(Component::NationalCheckDigits), 0);
+    assert_eq!(
+        Country::Hungary
+            .bban_spec()
+            .component_length(Component::NationalCheckDigits),
+        2
+    );
+    assert_eq!(CharClass::Alphanumeric.to_string(), "c");
+
+    // Every country's segments follow each other and add up to its length.
+    let countries = (b'A'..=b'Z')
+        .flat_map(|first| (b'A'..=b'Z').map(move |second| [first, second]))
+        .filter_map(|code| Country::from_str(std::str::from_utf8(&code).unwrap()).ok());
+    let mut count = 0;
+    for country in countries {
+        let spec = country.bban_spec();
+        let mut offset = 0;
+        for segment in spec.segments() {
+            assert_eq!(segment.offset, offset, "{:?}", country);
+            offset += segment.length;
+        }
+        assert_eq!(spec.length() + 4, country.length(), "{:?}", country);
+        assert!(spec.component_length(Component::BankCode) > 0);
+        assert!(spec.component_length(Component::AccountNumber) > 0);
+        count += 1;
+    }
+    assert_eq!(count, 79);
+
+    // The accessors use the same layout.
+    let iban = validate("IE29 AIBK 9311 5212 3456 78").unwrap();
+    assert_eq!(iban.bank_code(), "AIBK");
+    assert_eq!(iban.account_number(), "12345678");
+    let iban = validate("CZ65 0800 0000 1920 0014 5399").unwrap();
+    assert_eq!(iban.bank_code(), "0800");
+    assert_eq!(iban.account_number(), "0000192000145399");
+    let iban = validate("XK05 1212 0123 4567 8906").unwrap();
+    assert_eq!(iban.bank_code(), "1212");
+    assert_eq!(iban.account_number(), "0123456789");
+    let iban = validate("SC18 SSCB 1101 0000 0000 0000 1497 USD").unwrap();
+    assert_eq!(iban.bank_code(), "SSCB11");
+    assert_eq!(iban.account_number(), "0000000000001497");
+}
+
+#[test]
+fn iban_branch_code() {
+    let branch_code = |iban| validate(iban).unwrap().branch_code().map(str::to_string);
+    let some = |code: &str| Some(code.to_string());
+
+    assert_eq!(branch_code("GB29 NWBK 6016 1331 9268 19"), some("601613"));
+    assert_eq!(branch_code("IE29 AIBK 9311 5212 3456 78"), some("931152"));
+    assert_eq!(
+        branch_code("FR14 2004 1010 0505 0001 3M02 606"),
+        some("01005")
+    );
+    assert_eq!(
+        branch_code("IT60 X054 2811 1010 0000 0123 456"),
+        some("11101")
+    );
+    assert_eq!(branch_code("ES91 2100 0418 4502 0005 1332"), some("0418"));
+    assert_eq!(branch_code("PT50 0002 0123 1234 5678 9015 4"), some("0123"));
+    assert_eq!(
+        branch_code("BR97 0036 0305 0000 1000 9795 493P 1"),
+        some("00001")
+    );
+    assert_eq!(
+        branch_code("MU17 BOMM 0101 1010 3030 0200 000M UR"),
+        some("01")
+    );
+    assert_eq!(branch_code("DE89 3704 0044 0532 0130 00"), None);
+    assert_eq!(branch_code("BE68 5390 0754 7034"), None);
+
+    let iban = validate_ref("GB29NWBK60161331926819").unwrap();
+    assert_eq!(iban.branch_code(), Some("601613"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn iban_to_print_format() {
+    let iban = validate("GB82WEST12345698765432").unwrap();
+    assert_eq!(iban.to_print_format(), "GB82 WEST 1234 5698 7654 32");
+
+    // Printing and validating again gives the same IBAN.
+    let malta = validate("MT84MALT011000012345MTLCAST001S").unwrap();
+    assert_eq!(validate(malta.to_print_format()).unwrap(), malta);
+}
+
+#[test]
+fn national_check_digits_report() {
+    use schwifty::bban::NationalCheck;
+
+    let check_digits = |iban| {
+        let iban = validate(iban).unwrap();
+        (
+            iban.national_check_digits().map(str::to_string),
+            iban.verify_national_check(),
+        )
+    };
+    let verified = |digits: &str| (Some(digits.to_string()), NationalCheck::Verified);
+
+    assert_eq!(check_digits("BE68 5390 0754 7034"), verified("34"));
+    assert_eq!(
+        check_digits("AL47 2121 1009 0000 0002 3569 8741"),
+        verified("9")
+    );
+    // The Hungarian check digits aren't next to each other.
+    assert_eq!(
+        check_digits("HU42 1177 3016 1111 1018 0000 0000"),
+        (None, NationalCheck::Verified)
+    );
+    assert_eq!(check_digits("RS35 2600 0560 1001 6113 79"), verified("79"));
+    assert_eq!(
+        check_digits("PT50 0002 0123 1234 5678 9015 4"),
+        verified("54")
+    );
+    assert_eq!(
+        check_digits("NO93 8601 1117 947"),
+        (Some("7".to_string()), NationalCheck::NotVerifiable)
+    );
+    assert_eq!(
+        check_digits("CZ65 0800 0000 1920 0014 5399"),
+        (None, NationalCheck::Verified)
+    );
+    assert_eq!(
+        check_digits("AT61 1904 3002 3457 3201"),
+        (None, NationalCheck::NotApplicable)
+    );
+
+    assert_eq!(
+        Country::Serbia.verify_national_check("260005601001611379"),
+        NationalCheck::Verified
+    );
+    assert_eq!(
+        Country::Serbia.verify_national_check("260005601001611378"),
+        NationalCheck::Failed
+    );
+    assert_eq!(
+        Country::Serbia.verify_national_check("26000560100161137"),
+        NationalCheck::Failed
+    );
+    assert_eq!(
+        Country::Serbia.verify_national_check("26000560100161137é"),
+        NationalCheck::Failed
+    );
+
+    // Only reported, validate accepts IBANs with wrong Serbian check digits.
+    let iban = checksum::repair("RS35 2600 0560 1001 6113 78").unwrap();
+    assert_eq!(iban.national_check_digits(), Some("78"));
+    assert_eq!(iban.verify_national_check(), NationalCheck::Failed);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn iban_from_parts() {
+    let iban = Iban::from_parts(Country::Germany, "37040044", "", "532013000").unwrap();
+    assert_eq!(iban.raw(), "DE89370400440532013000");
+
+    let iban = Iban::from_parts(Country::UnitedKingdom, "NWBK", "601613", "31926819").unwrap();
+    assert_eq!(iban.raw(), "GB29NWBK60161331926819");
+
+    let iban = Iban::from_parts(Country::CostaRica, "152", "", "2001026284066").unwrap();
+    assert_eq!(iban.raw(), "CR05015202001026284066");
+
+    // National check digits are computed.
+    let iban = Iban::from_parts(Country::Belgium, "539", "", "75470").unwrap();
+    assert_eq!(iban.raw(), "BE68539007547034");
+
+    let iban = Iban::from_parts(Country::Albania, "212", "1100", "235698741").unwrap();
+    assert_eq!(iban.raw(), "AL47212110090000000235698741");
+
+    // Lowercase letters give the same IBAN as uppercase ones.
+    let iban = Iban::from_parts(Country::UnitedKingdom, "nwbk", "601613", "31926819").unwrap();
+    assert_eq!(iban.raw(), "GB29NWBK60161331926819");
+    let iban = Iban::from_parts(Country::Italy, "05428", "11101", "x23456").unwrap();
+    let upper = Iban::from_parts(Country::Italy, "05428", "11101", "X23456").unwrap();
+    assert_eq!(iban.raw(), upper.raw());
+    assert_eq!(iban.raw(), iban.raw().to_ascii_uppercase());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn iban_from_parts_invalid() {
+    assert_eq!(
+        Iban::from_parts(Country::Germany, "370400440", "", "532013000").unwrap_err(),
+        ValidationError::InvalidComponentLength {
+            country: Country::Germany,
+            component: Component::BankCode,
+            expected: 8,
+            actual: 9
+        }
+    );
+    assert_eq!(
+        Iban::from_parts(Country::Germany, "37040044", "1", "532013000").unwrap_err(),
+        ValidationError::InvalidComponentLength {
+            country: Country::Germany,
+            component: Component::BranchCode,
+            expected: 0,
+            actual: 1
+        }
+    );
+    assert_eq!(
+        Iban::from_parts(Country::UnitedKingdom, "1234", "601613", "31926819").unwrap_err(),
+        ValidationError::InvalidFormat {
+            country: Country::UnitedKingdom,
+            position: 4,
+            component: Component::BankCode
+        }
+    );
+    assert_eq!(
+        Iban::from_parts(Country::Albania, "2A2", "1100", "235698741").unwrap_err(),
+        ValidationError::InvalidFormat {
+            country: Country::Albania,
+            position: 5,
+            component: Component::BankCode
+        }
+    );
+    assert_eq!(
+        Iban::from_parts(Country::CzechRepublic, "0800", "", "1920001454").unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country: Country::CzechRepublic,
+            component: Component::AccountNumber
+        }
+    );
+    assert_eq!(
+        Iban::from_parts(Country::Brazil, "00360305", "00001", "0009795493").unwrap_err(),
+        ValidationError::Unsupported {
+            country: Country::Brazil,
+            component: Component::AccountType
+        }
+    );
+}
+
+#[test]
+fn checksum_check_digits() {
+    let check_digits = checksum::check_digits(Country::UnitedKingdom, "NWBK60161331926819");
+    assert_eq!(check_digits.unwrap(), 29);
+
+    let check_digits = checksum::check_digits(Country::Finland, "12345600000785");
+    assert_eq!(check_digits.unwrap(), 21);
+
+    assert_eq!(
+        checksum::check_digits(Country::UnitedKingdom, "NWBK6016133192681").unwrap_err(),
+        ValidationError::InvalidLength {
+            country: Country::UnitedKingdom,
+            expected: 22,
+            actual: 21
+        }
+    );
+    assert_eq!(
+        checksum::check_digits(Country::UnitedKingdom, "NWBK-6016133192681").unwrap_err(),
+        ValidationError::InvalidChar { position: 8 }
+    );
+}
+
+#[test]
+fn checksum_repair() {
+    let iban = checksum::repair("GB00 NWBK 6016 1331 9268 19").unwrap();
+    assert_eq!(iban.raw(), "GB29NWBK60161331926819");
+    assert_eq!(iban.check_digits(), "29");
+
+    let iban = checksum::repair("BE86 5390 0754 7034").unwrap();
+    assert_eq!(iban.check_digits(), "68");
+
+    // Already valid IBANs are left alone.
+    let iban = checksum::repair("DE89 3704 0044 0532 0130 00").unwrap();
+    assert_eq!(iban.raw(), "DE89370400440532013000");
+
+    // Other errors can't be repaired.
     assert!(matches!(
-        validate("FR"),
-        Err(ValidationError::InvalidLength)
+        checksum::repair("GB00 NWBK 6016 1331 9268 1"),
+        Err(ValidationError::InvalidLength { .. })
     ));
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn suggest_corrections_typos() {
+    let original = "GB82WEST12345698765432";
+
+    // Similar looking characters are the most likely mistake.
+    let suggestions = suggest_corrections("GB82 WEST I234 5698 7654 32");
+    assert_eq!(suggestions[0].raw(), original);
+
+    let suggestions = suggest_corrections("GB82 WEST 1234 5698 7654 23");
+    assert!(suggestions.iter().any(|iban| iban.raw() == original));
+
+    let suggestions = suggest_corrections("GB82 WEST 1234 5698 7604 32");
+    assert!(suggestions.iter().any(|iban| iban.raw() == original));
+
+    for iban in suggest_corrections("DE89 3704 0044 0532 0130 01") {
+        assert!(validate(iban.raw()).is_ok());
+    }
+
+    assert!(suggest_corrections(original).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn diagnose_findings() {
+    assert!(diagnose("GB82 WEST 1234 5698 7654 32").is_empty());
+
+    // Every problem is reported, not just the first.
+    let findings = diagnose("GB82 WEST 1234-5698 7654 32");
+    assert_eq!(
+        findings,
+        vec![
+            Finding::Invalid(ValidationError::InvalidChar { position: 12 }),
+            Finding::Invalid(ValidationError::InvalidLength {
+                country: Country::UnitedKingdom,
+                expected: 22,
+                actual: 23
+            }),
+        ]
+    );
+
+    let findings = diagnose("BE68 5390 0754 7035");
+    assert_eq!(
+        findings,
+        vec![
+            Finding::Invalid(ValidationError::CountryCheckFailed {
+                country: Country::Belgium,
+                component: Component::NationalCheckDigits
+            }),
+            Finding::Invalid(ValidationError::InvalidIban {
+                country: Country::Belgium,
+                expected: 41
+            }),
+        ]
+    );
+
+    let findings = diagnose("GB82 west 1234 5698 7654 32");
+    assert_eq!(findings.len(), 5);
+    assert_eq!(
+        findings[0],
+        Finding::Invalid(ValidationError::InvalidFormat {
+            country: Country::UnitedKingdom,
+            position: 4,
+            component: Component::BankCode
+        })
+    );
+    assert_eq!(findings[0].severity(), Severity::Error);
+    assert_eq!(findings[1], Finding::Lowercase { position: 4 });
+    assert_eq!(findings[1].severity(), Severity::Warning);
+
+    assert_eq!(
+        diagnose("GBAK WEST 1234 5698 7654 32"),
+        vec![Finding::Invalid(ValidationError::InvalidChar {
+            position: 2
+        })]
+    );
+}
+
+// Gives the BBAN the right IBAN check digits, so only its national check can fail.
+fn assert_national_check_fails(country: Country, bban: &str) {
+    let check_digits = checksum::check_digits(country, bban).unwrap();
+    assert_eq!(
+        validate(format!("{}{:02}{}", country.code(), check_digits, bban)).unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country,
+            component: bban::Component::NationalCheckDigits
+        }
+    );
+}
+
+#[test]
+fn rib_key() {
+    use schwifty::bban::NationalCheck;
+
+    let iban = validate("FR14 2004 1010 0505 0001 3M02 606").unwrap();
+    assert_eq!(iban.national_check_digits().unwrap(), "06");
+    assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+
+    // The examples of the IBAN registry and of the French banks.
+    for input in [
+        "FR76 3000 6000 0112 3456 7890 189",
+        "FR76 3000 1007 9412 3456 7890 185",
+        "FR76 1010 7001 0112 3456 7890 129",
+        "MC58 1122 2000 0101 2345 6789 030",
+    ] {
+        let iban = validate(input).unwrap();
+        assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+    }
+
+    assert_national_check_fails(Country::France, "30006000011234567890190");
+    assert_national_check_fails(Country::France, "20041010050500013M02605");
+    assert_national_check_fails(Country::Monaco, "11222000010123456789031");
+}
+
+#[test]
+fn spanish_control_digits() {
+    use schwifty::bban::NationalCheck;
+
+    let iban = validate("ES91 2100 0418 4502 0005 1332").unwrap();
+    assert_eq!(iban.national_check_digits().unwrap(), "45");
+    assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+
+    // Examples of Spanish banks.
+    for input in [
+        "ES79 2100 0813 6101 2345 6789",
+        "ES76 2077 0024 0031 0257 5766",
+        "ES60 0049 1500 0512 3456 7892",
+    ] {
+        let iban = validate(input).unwrap();
+        assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+    }
+
+    assert_eq!(
+        Country::Spain.verify_national_check("21000418460200051332"),
+        NationalCheck::Failed
+    );
+    assert_national_check_fails(Country::Spain, "21000418450200051333");
+    assert_national_check_fails(Country::Spain, "21000813710123456789");
+}
+
+#[test]
+fn italian_cin() {
+    use schwifty::bban::NationalCheck;
+
+    let iban = validate("IT60 X054 2811 1010 0000 0123 456").unwrap();
+    assert_eq!(iban.national_check_digits().unwrap(), "X");
+    assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+    let iban = validate("SM86 U032 2509 8000 0000 0270 100").unwrap();
+    assert_eq!(iban.national_check_digits().unwrap(), "U");
+    let spec = Country::Italy.bban_spec();
+    let cin = spec.component(bban::Component::NationalCheckDigits).next();
+    assert_eq!(
+        cin.map(|segment| (segment.offset, segment.length)),
+        Some((0, 1))
+    );
+
+    let iban = validate("SM76 P085 4009 8121 2345 6789 123").unwrap();
+    assert_eq!(iban.verify_national_check(), NationalCheck::Verified);
+
+    assert_eq!(
+        Country::Italy.verify_national_check("Y0542811101000000123456"),
+        NationalCheck::Failed
+    );
+    assert_national_check_fails(Country::Italy, "Y0542811101000000123456");
+    // A widely copied example IBAN, which has the right IBAN check digits but not the CIN.
+    assert_eq!(
+        validate("IT40 S054 2811 1010 0000 0123 456").unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country: Country::Italy,
+            component: bban::Component::NationalCheckDigits
+        }
+    );
+    assert_national_check_fails(Country::SanMarino, "V0322509800000000270100");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn national_check_digits_from_parts() {
+    // Check digits we can compute are filled in.
+    let iban = Iban::from_parts(Country::Serbia, "260", "", "0056010016113").unwrap();
+    assert_eq!(iban.raw(), "RS35260005601001611379");
+    let iban = Iban::from_parts(Country::Hungary, "117", "7301", "111110180000000").unwrap();
+    assert_eq!(iban.raw(), "HU42117730161111101800000000");
+    let iban = Iban::from_parts(Country::Monaco, "11222", "00001", "01234567890").unwrap();
+    assert_eq!(iban.to_string(), "MC58 1122 2000 0101 2345 6789 030");
+    let iban = Iban::from_parts(Country::Mauritania, "20", "101", "1234567").unwrap();
+    assert_eq!(iban.to_string(), "MR13 0002 0001 0100 0012 3456 753");
+    let iban = Iban::from_parts(Country::France, "20041", "01005", "0500013M026").unwrap();
+    assert_eq!(iban.raw(), "FR1420041010050500013M02606");
+
+    // Remainders of 1 and 0 give the digits 1 and 0.
+    let iban = Iban::from_parts(Country::Spain, "0000", "0017", "15").unwrap();
+    assert_eq!(iban.national_check_digits().unwrap(), "10");
+
+    let iban = Iban::from_parts(Country::Italy, "05428", "11101", "123456").unwrap();
+    assert_eq!(iban.raw(), "IT60X0542811101000000123456");
+}
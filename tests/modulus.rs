@@ -0,0 +1,109 @@
+#![cfg(feature = "std")]
+
+use schwifty::bban::NationalCheck;
+use schwifty::modulus::ModulusRules;
+use schwifty::registry::ParseError;
+use schwifty::*;
+
+const VALACDOS: &str = "\
+089000 089999 MOD10    0    0    0    0    0    0    7    1    3    7    1    3    7    1
+107999 107999 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1
+134012 134020 MOD11    0    0    0    0    0    0    7    5    8    3    4    6    2    1   4
+309070 309076 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1   2
+309070 309076 DBLAL    2    1    2    1    2    1    2    1    2    1    2    1    2    1   9
+180002 180002 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1  14
+772798 772798 MOD11    1    2    3    4    5    6    8    7    6    5    4    3    2    1   7
+090128 090128 MOD11    1    2    3    4    5    6    8    7    6    5    4    3    2    1   8
+871427 871427 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1  10
+871427 871427 MOD11    0    0    0    0    0    0    3    7    1    3    7    1    3    1  11
+938000 938696 MOD11    7    6    5    4    3    2    7    6    5    4    3    2    0    0   5
+938000 938696 DBLAL    2    1    2    1    2    1    2    1    2    1    2    1    2    0   5
+";
+
+const SCSUBTAB: &str = "938173 938017\n";
+
+#[test]
+fn modulus_checks() {
+    let rules = ModulusRules::from_valacdos(VALACDOS)
+        .unwrap()
+        .with_substitutions(SCSUBTAB)
+        .unwrap();
+
+    // The standard examples of the VocaLink specification.
+    assert_eq!(rules.verify("089999", "66374958"), NationalCheck::Verified);
+    assert_eq!(rules.verify("107999", "88837491"), NationalCheck::Verified);
+    assert_eq!(rules.verify("089999", "66374959"), NationalCheck::Failed);
+    assert_eq!(rules.verify("107999", "88837492"), NationalCheck::Failed);
+
+    // Exception 4: the remainder is the last two digits.
+    assert_eq!(rules.verify("134020", "10000210"), NationalCheck::Verified);
+    assert_eq!(rules.verify("134020", "10000211"), NationalCheck::Failed);
+
+    // Exception 7: the first weights are ignored if g is 9.
+    assert_eq!(rules.verify("772798", "10000094"), NationalCheck::Verified);
+
+    // Exception 8: checked with sort code 090126.
+    assert_eq!(rules.verify("090128", "12345677"), NationalCheck::Verified);
+
+    // Exceptions 10 and 11: one of the checks has to pass.
+    assert_eq!(rules.verify("871427", "12345675"), NationalCheck::Verified);
+    assert_eq!(rules.verify("871427", "12345670"), NationalCheck::Failed);
+
+    // Exception 5: g and h are check digits, for the substituted sort code.
+    assert_eq!(rules.verify("938173", "10000091"), NationalCheck::Verified);
+    assert_eq!(rules.verify("938173", "10000092"), NationalCheck::Failed);
+    assert_eq!(rules.verify("938017", "10000091"), NationalCheck::Verified);
+
+    // Exception 2: the weights depend on a and g, then exception 9 checks the account
+    // with sort code 309634. The weights of the second check are made up.
+    assert_eq!(rules.verify("309070", "12345677"), NationalCheck::Verified);
+    assert_eq!(rules.verify("309070", "99345694"), NationalCheck::Verified);
+    assert_eq!(rules.verify("309070", "12345657"), NationalCheck::Verified);
+    assert_eq!(rules.verify("309070", "12345678"), NationalCheck::Failed);
+
+    // Exception 14: the last digit is left out if the first check fails.
+    assert_eq!(rules.verify("180002", "98093517"), NationalCheck::Verified);
+    assert_eq!(rules.verify("180002", "00000190"), NationalCheck::Verified);
+    assert_eq!(rules.verify("180002", "00000192"), NationalCheck::Failed);
+    assert_eq!(rules.verify("180002", "98093518"), NationalCheck::Failed);
+    assert_eq!(
+        rules.verify("000000", "12345678"),
+        NationalCheck::NotVerifiable
+    );
+    assert_eq!(rules.verify("08999", "66374958"), NationalCheck::Failed);
+    assert_eq!(rules.verify("089999", "6637495A"), NationalCheck::Failed);
+}
+
+#[test]
+fn modulus_iban() {
+    let rules = ModulusRules::from_valacdos(VALACDOS).unwrap();
+    let iban = |sort_code, account_number| {
+        Iban::from_parts(Country::UnitedKingdom, "NWBK", sort_code, account_number).unwrap()
+    };
+
+    let valid = iban("089999", "66374958");
+    assert_eq!(rules.verify_iban(&valid), NationalCheck::Verified);
+    assert_eq!(rules.validate(valid.raw()).unwrap(), valid);
+    assert_eq!(
+        rules
+            .validate(iban("089999", "66374959").raw())
+            .unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country: Country::UnitedKingdom,
+            component: bban::Component::AccountNumber
+        }
+    );
+
+    let german = validate("DE89 3704 0044 0532 0130 00").unwrap();
+    assert_eq!(rules.verify_iban(&german), NationalCheck::NotApplicable);
+    assert!(rules.validate("GB82 WEST 1234 5698 7654 32").is_ok());
+
+    assert_eq!(
+        ModulusRules::from_valacdos("089000 089999 MOD12 0 0 0 0 0 0 7 1 3 7 1 3 7 1").unwrap_err(),
+        ParseError::InvalidLine { line: 1 }
+    );
+    assert!(ModulusRules::from_valacdos("")
+        .unwrap()
+        .with_substitutions("938173")
+        .is_err());
+}
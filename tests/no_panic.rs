@@ -0,0 +1,165 @@
+// Property tests making sure no input can make the public functions panic.
+// The inputs come from a small seeded generator, so failures are reproducible.
+
+use schwifty::*;
+
+const VALID: [&str; 6] = [
+    "GB82 WEST 1234 5698 7654 32",
+    "AL47 2121 1009 0000 0002 3569 8741",
+    "BE68 5390 0754 7034",
+    "CZ65 0800 0000 1920 0014 5399",
+    "FR14 2004 1010 0505 0001 3M02 606",
+    "MU17 BOMM 0101 1010 3030 0200 000M UR",
+];
+
+// xorshift64*, good enough to generate test input.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn char(&mut self) -> char {
+        let pick = |rng: &mut Rng, chars: &str| {
+            let chars: Vec<char> = chars.chars().collect();
+            chars[rng.below(chars.len())]
+        };
+
+        match self.below(8) {
+            0 | 1 => pick(self, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+            2 => pick(self, "abcxyz -_./\t\n\u{a0}\u{3000}"),
+            3 => pick(self, "éÉßøÆçü"),
+            4 => pick(self, "БГДЖЗИЛПФЦЧШЩЭЮЯ"),
+            5 => pick(self, "０１２３４５６７８９ＡＢＣ"),
+            6 => pick(self, "٠١٢٣߀🏦💶\u{301}\u{200b}"),
+            _ => loop {
+                if let Some(ch) = char::from_u32(self.below(0x11_0000) as u32) {
+                    break ch;
+                }
+            },
+        }
+    }
+
+    fn string(&mut self) -> String {
+        let length = self.below(40);
+        (0..length).map(|_| self.char()).collect()
+    }
+
+    // A valid IBAN with a few characters replaced, inserted or removed.
+    fn mutated(&mut self) -> String {
+        let mut chars: Vec<char> = VALID[self.below(VALID.len())].chars().collect();
+        for _ in 0..=self.below(3) {
+            let position = self.below(chars.len());
+            match self.below(3) {
+                0 => chars[position] = self.char(),
+                1 => chars.insert(position, self.char()),
+                _ => {
+                    chars.remove(position);
+                }
+            }
+        }
+        chars.into_iter().collect()
+    }
+
+    fn input(&mut self) -> String {
+        if self.below(2) == 0 {
+            self.string()
+        } else {
+            self.mutated()
+        }
+    }
+}
+
+#[test]
+fn validate_never_panics() {
+    let mut rng = Rng(0x5EED);
+    for _ in 0..20_000 {
+        let input = rng.input();
+        let _ = validate(&input);
+        let _ = validate_ref(&input);
+        let _ = validate_partial(&input);
+        let _ = validate_bic(&input);
+
+        let bytes: Vec<u8> = (0..rng.below(40)).map(|_| rng.next() as u8).collect();
+        let _ = validate_ref(&bytes);
+        #[cfg(feature = "std")]
+        let _ = diagnose(&input);
+        let _ = checksum::repair(&input);
+    }
+}
+
+#[test]
+fn non_ascii_is_invalid_char() {
+    let mut rng = Rng(0xA5C11);
+    for _ in 0..2000 {
+        let input = rng.mutated();
+        let non_ascii = input
+            .split_whitespace()
+            .flat_map(str::chars)
+            .any(|ch| !ch.is_ascii());
+        if non_ascii {
+            assert!(validate(&input).is_err(), "{:?} is valid", input);
+            assert!(validate_ref(&input).is_err(), "{:?} is valid", input);
+        }
+    }
+
+    assert_eq!(
+        validate("Dé82 WEST 1234 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidChar { position: 1 }
+    );
+    assert_eq!(
+        validate("GB82 WEST １２34 5698 7654 32").unwrap_err(),
+        ValidationError::InvalidChar { position: 8 }
+    );
+    #[cfg(feature = "std")]
+    assert!(
+        diagnose("GB8éWEST1234569876543").contains(&Finding::Invalid(
+            ValidationError::InvalidChar { position: 3 }
+        ))
+    );
+    assert_eq!(
+        validate("CZ65 0800 0000 1920 0014 539A").unwrap_err(),
+        ValidationError::InvalidFormat {
+            country: Country::CzechRepublic,
+            position: 23,
+            component: bban::Component::AccountNumber
+        }
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parts_never_panic() {
+    let mut rng = Rng(0xB4A7);
+    let countries = [
+        Country::Albania,
+        Country::Belgium,
+        Country::CzechRepublic,
+        Country::Germany,
+        Country::UnitedKingdom,
+    ];
+    for _ in 0..2000 {
+        let country = countries[rng.below(countries.len())];
+        let (bank_code, branch_code, account_number) = (rng.string(), rng.string(), rng.string());
+        let _ = Iban::from_parts(country, &bank_code, &branch_code, &account_number);
+        let _ = checksum::check_digits(country, &account_number);
+        let _ = country.verify_national_check(&account_number);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn suggest_corrections_never_panics() {
+    let mut rng = Rng(0x7E57);
+    for _ in 0..200 {
+        let _ = suggest_corrections(rng.input());
+    }
+}
@@ -0,0 +1,795 @@
+#![cfg(feature = "std")]
+
+use schwifty::registry::{BankRegistry, ParseError, Registry};
+use schwifty::*;
+
+// A line of the Bundesbank file, with the fields at their fixed positions.
+fn blz_line(blz: &str, feature: char, name: &str, short_name: &str, bic: &str) -> String {
+    blz_line_method(blz, feature, name, short_name, bic, "09")
+}
+
+fn blz_line_method(
+    blz: &str,
+    feature: char,
+    name: &str,
+    short_name: &str,
+    bic: &str,
+    method: &str,
+) -> String {
+    format!(
+        "{:8}{}{:58}{:5}{:35}{:27}{:5}{:11}{:2}{:06}{}{}{:08}",
+        blz,
+        feature,
+        name,
+        "60311",
+        "Frankfurt am Main",
+        short_name,
+        "",
+        bic,
+        method,
+        1,
+        'U',
+        '0',
+        0
+    )
+}
+
+#[test]
+fn registry_bundesbank() {
+    let content = [
+        blz_line(
+            "37040044",
+            '1',
+            "Commerzbank",
+            "Commerzbank Köln",
+            "COBADEFFXXX",
+        ),
+        blz_line("37040044", '2', "Commerzbank", "Commerzbank Bonn", ""),
+        blz_line(
+            "50070010",
+            '1',
+            "Deutsche Bank",
+            "Deutsche Bank Ffm",
+            "DEUTDEFFXXX",
+        ),
+        blz_line("50010517", '1', "ING-DiBa", "ING-DiBa Frankfurt", ""),
+    ]
+    .join("\r\n");
+    let registry = Registry::from_bundesbank(&content).unwrap();
+    assert_eq!(registry.len(), 3);
+
+    let iban = validate("DE89 3704 0044 0532 0130 00").unwrap();
+    let bank = iban.bank_in(&registry).unwrap();
+    assert_eq!(bank.name, "Commerzbank");
+    assert_eq!(bank.short_name, "Commerzbank Köln");
+    assert_eq!(bank.bic.unwrap().as_str(), "COBADEFFXXX");
+
+    let bank = registry.lookup(Country::Germany, "50010517").unwrap();
+    assert_eq!((bank.short_name, bank.bic), ("ING-DiBa Frankfurt", None));
+    assert_eq!(registry.lookup(Country::Germany, "50070011"), None);
+    assert_eq!(registry.lookup(Country::Austria, "50070010"), None);
+
+    let content = format!("{}\n37040044", content);
+    assert_eq!(
+        Registry::from_bundesbank(&content).unwrap_err(),
+        ParseError::InvalidLine { line: 5 }
+    );
+}
+
+#[test]
+fn registry_csv() {
+    let six = "\u{feff}IID;Bank/institution name;Short name;BIC\n\
+               100;Schweizerische Nationalbank;SNB;SNBZCHZZXXX\n\
+               \"700\";\"Zürcher Kantonalbank\";\"ZKB\";\"ZKBKCHZZ80A\"\n";
+    let registry = Registry::from_six(six).unwrap();
+    let bank = registry.lookup(Country::Switzerland, "00700").unwrap();
+    assert_eq!(
+        (bank.name, bank.short_name),
+        ("Zürcher Kantonalbank", "ZKB")
+    );
+    assert_eq!(bank.bic.unwrap().as_str(), "ZKBKCHZZ80A");
+    assert!(registry.is_complete(Country::Switzerland));
+    assert!(!registry.is_complete(Country::Liechenstein));
+
+    // Banks in Liechtenstein are listed too, with the same bank codes.
+    let six = "IID;Bank/institution name;Short name;Country code;BIC\n\
+               100;Schweizerische Nationalbank;SNB;CH;SNBZCHZZXXX\n\
+               8810;LGT Bank AG;LGT Bank;LI;BLFLLI2X\n";
+    let registry = Registry::from_six(six).unwrap();
+    let iban = validate("LI21 0881 0000 2324 013A A").unwrap();
+    assert_eq!(iban.bank_in(&registry).unwrap().name, "LGT Bank AG");
+    assert_eq!(registry.lookup(Country::Switzerland, "08810"), None);
+    assert!(registry.lookup(Country::Switzerland, "00100").is_some());
+
+    let oenb = "Verzeichnis der Bankleitzahlen\n\n\
+                Kennzeichen;Bankleitzahl;Bankenname;SWIFT-Code\n\
+                Hauptanstalt;12000;\"UniCredit Bank Austria AG\";BKAUATWW\n\
+                Hauptanstalt;20111;Erste Bank der oesterreichischen Sparkassen AG;GIBAATWWXXX\n";
+    let registry = Registry::from_oenb(oenb).unwrap();
+    let bank = registry.lookup(Country::Austria, "12000").unwrap();
+    assert_eq!(bank.short_name, "UniCredit Bank Austria AG");
+    assert_eq!(bank.bic.unwrap().as_str(), "BKAUATWW");
+
+    let nbb = "T_Identification_Number,Biccode,T_Institutions_Dutch,T_Institutions_French,\
+               T_Institutions_German,T_Institutions_English\n\
+               000-000,BPOTBEB1,bpost bank,bpost banque,,bpost bank\n\
+               001-049,GEBABEBB,BNP Paribas Fortis,BNP Paribas Fortis,,BNP Paribas Fortis\n\
+               050-099,VRIJ-LIBRE,,,,\n\
+               890,-,vdk bank,vdk banque,,vdk bank\n";
+    let mut registry = Registry::from_nbb(nbb).unwrap();
+    assert_eq!(registry.len(), 3);
+    let iban = validate("BE71 0961 2345 6769").unwrap();
+    assert_eq!(iban.bank_in(&registry), None);
+    // Bank codes in a range give the whole range.
+    let bank = registry.lookup(Country::Belgium, "012").unwrap();
+    assert_eq!((bank.bank_code, bank.name), ("001", "BNP Paribas Fortis"));
+    assert_eq!(bank.last_bank_code, "049");
+    let bic = validate_bic("GEBABEBB").unwrap();
+    let banks: Vec<_> = registry.lookup_bic(&bic).collect();
+    assert_eq!(
+        (banks[0].bank_code, banks[0].last_bank_code),
+        ("001", "049")
+    );
+    let bank = registry.lookup(Country::Belgium, "890").unwrap();
+    assert_eq!(bank.last_bank_code, "890");
+    assert_eq!(registry.lookup(Country::Belgium, "890").unwrap().bic, None);
+
+    let dutch = "Identifier;BIC;Naam betaalinstelling\nABNA;ABNANL2A;ABN AMRO BANK N.V.\n\
+                 ingb;INGBNL2A;ING BANK N.V.\n";
+    registry.extend(Registry::from_dutch_bic_list(dutch).unwrap());
+    let iban = validate("NL91 ABNA 0417 1643 00").unwrap();
+    assert_eq!(iban.bank_in(&registry).unwrap().name, "ABN AMRO BANK N.V.");
+    assert!(registry.lookup(Country::Netherlands, "INGB").is_some());
+    assert!(registry.lookup(Country::Belgium, "000").is_some());
+    assert_eq!(registry.banks().count(), 5);
+
+    let overlapping = "T_Identification_Number,Biccode,T_Institutions_English\n\
+                       001-049,GEBABEBB,BNP Paribas Fortis\n\
+                       030,,Overlapping bank\n";
+    let registry = Registry::from_nbb(overlapping).unwrap();
+    let range = |bank_code| {
+        let bank = registry.lookup(Country::Belgium, bank_code).unwrap();
+        (bank.name, bank.bank_code, bank.last_bank_code)
+    };
+    assert_eq!(range("029"), ("BNP Paribas Fortis", "001", "049"));
+    assert_eq!(range("030"), ("Overlapping bank", "030", "030"));
+    assert_eq!(range("040"), ("BNP Paribas Fortis", "001", "049"));
+    assert!(registry.lookup(Country::Belgium, "050").is_none());
+
+    assert_eq!(
+        Registry::from_oenb("BLZ;Kennzeichen\n12000;H\n").unwrap_err(),
+        ParseError::MissingColumns
+    );
+    assert_eq!(
+        Registry::from_six("IID;Bankname\n100;SNB\n1000000;Too long\n").unwrap_err(),
+        ParseError::InvalidLine { line: 3 }
+    );
+    assert_eq!(
+        Registry::from_six("IID;Bankname;Land\n100;SNB;CH\n100;Bank;DE\n").unwrap_err(),
+        ParseError::InvalidLine { line: 3 }
+    );
+}
+
+#[test]
+fn registry_reverse_lookup() {
+    let content = [
+        blz_line(
+            "37040044",
+            '1',
+            "Commerzbank",
+            "Commerzbank Köln",
+            "COBADEFFXXX",
+        ),
+        blz_line(
+            "37080040",
+            '1',
+            "Commerzbank",
+            "Commerzbank Köln",
+            "DRESDEFF370",
+        ),
+        blz_line(
+            "50040000",
+            '1',
+            "Commerzbank",
+            "Commerzbank Ffm",
+            "COBADEFFXXX",
+        ),
+        blz_line(
+            "50070010",
+            '1',
+            "Deutsche Bank",
+            "Deutsche Bank Ffm",
+            "DEUTDEFFXXX",
+        ),
+        blz_line(
+            "50070024",
+            '1',
+            "Deutsche Bank",
+            "Deutsche Bank Ffm",
+            "DEUTDEDBFRA",
+        ),
+    ]
+    .join("\n");
+    let registry = Registry::from_bundesbank(&content).unwrap();
+
+    let bank_codes = |bic| {
+        let bic = validate_bic(bic).unwrap();
+        let banks = registry.lookup_bic(&bic);
+        banks.map(|bank| bank.bank_code).collect::<Vec<_>>()
+    };
+    assert_eq!(bank_codes("COBADEFF"), ["37040044", "50040000"]);
+    assert_eq!(bank_codes("COBADEFFXXX"), ["37040044", "50040000"]);
+    assert_eq!(bank_codes("DRESDEFF"), ["37080040"]);
+    assert_eq!(bank_codes("DEUTDEDBFRA"), ["50070024"]);
+    assert!(bank_codes("DEUTDEDBXXX").is_empty());
+
+    // Only registries listing every bank of a country can tell a bank code is unassigned.
+    let iban = "DE89 3704 0044 0532 0130 00";
+    assert!(validate_with_registry(iban, &registry).is_ok());
+    let unknown = Iban::from_parts(Country::Germany, "12345678", "", "1234567").unwrap();
+    assert_eq!(
+        validate_with_registry(unknown.raw(), &registry).unwrap_err(),
+        ValidationError::UnknownBank {
+            country: Country::Germany
+        }
+    );
+    assert!(validate_with_registry("GB82 WEST 1234 5698 7654 32", &registry).is_ok());
+    assert!(validate_with_registry("DE89 3704 0044 0532 0130 01", &registry).is_err());
+
+    // Part of a directory can't tell that either.
+    let mut partial = registry.clone();
+    partial.set_complete(Country::Germany, false);
+    assert!(validate_with_registry(unknown.raw(), &partial).is_ok());
+    let mut combined = Registry::new();
+    combined.extend(partial);
+    assert!(!combined.is_complete(Country::Germany));
+    combined.extend(registry);
+    assert!(combined.is_complete(Country::Germany));
+}
+
+#[test]
+fn german_check_methods() {
+    let content = [
+        blz_line_method("10000000", '1', "Bank 00", "Bank 00", "", "00"),
+        blz_line_method("20000000", '1', "Bank 02", "Bank 02", "", "02"),
+        blz_line_method("30000000", '1', "Bank 06", "Bank 06", "", "06"),
+        blz_line_method("40000000", '1', "Bank 08", "Bank 08", "", "08"),
+        blz_line_method("50000000", '1', "Bank ZZ", "Bank ZZ", "", "ZZ"),
+        blz_line_method("60000000", '1', "Bank 09", "Bank 09", "", "09"),
+    ]
+    .join("\n");
+    let registry = Registry::from_bundesbank(&content).unwrap();
+    let check = |bank_code, account_number| {
+        let bank = registry.lookup(Country::Germany, bank_code).unwrap();
+        bank.verify_account_number(account_number)
+    };
+
+    assert_eq!(
+        check("10000000", "0009290701"),
+        bban::NationalCheck::Verified
+    );
+    assert_eq!(
+        check("10000000", "0539290858"),
+        bban::NationalCheck::Verified
+    );
+    assert_eq!(check("10000000", "0009290702"), bban::NationalCheck::Failed);
+    assert_eq!(check("10000000", "09290701"), bban::NationalCheck::Failed);
+    assert_eq!(
+        check("30000000", "1234567892"),
+        bban::NationalCheck::Verified
+    );
+    assert_eq!(
+        check("30000000", "1234567000"),
+        bban::NationalCheck::Verified
+    );
+    assert_eq!(check("30000000", "1234567893"), bban::NationalCheck::Failed);
+
+    // A remainder of 1 is check digit 0 with method 06, but not allowed with method 02.
+    assert_eq!(
+        check("30000000", "0000010090"),
+        bban::NationalCheck::Verified
+    );
+    assert_eq!(check("20000000", "0000010090"), bban::NationalCheck::Failed);
+
+    assert_eq!(
+        check("40000000", "0000012345"),
+        bban::NationalCheck::NotApplicable
+    );
+    assert_eq!(check("40000000", "0000612345"), bban::NationalCheck::Failed);
+    assert_eq!(
+        check("50000000", "1234567890"),
+        bban::NationalCheck::NotVerifiable
+    );
+    assert_eq!(
+        check("60000000", "1234567890"),
+        bban::NationalCheck::NotApplicable
+    );
+
+    let iban = |bank_code, account_number| {
+        let iban = Iban::from_parts(Country::Germany, bank_code, "", account_number).unwrap();
+        validate_with_registry(iban.raw(), &registry)
+    };
+    assert!(iban("10000000", "9290701").is_ok());
+    assert!(iban("50000000", "1234567890").is_ok());
+    assert_eq!(
+        iban("10000000", "9290702").unwrap_err(),
+        ValidationError::CountryCheckFailed {
+            country: Country::Germany,
+            component: bban::Component::AccountNumber
+        }
+    );
+}
+
+// Checks an account number with a bank using the method.
+fn check_method(method: &str, bank_code: &str, account_number: &str) -> bban::NationalCheck {
+    let content = blz_line_method(bank_code, '1', "Bank", "Bank", "", method);
+    let registry = Registry::from_bundesbank(&content).unwrap();
+    let bank = registry.lookup(Country::Germany, bank_code).unwrap();
+    bank.verify_account_number(&format!("{:0>10}", account_number))
+}
+
+#[test]
+fn german_check_method_examples() {
+    // Mostly the test account numbers of the descriptions of the methods, with the bank
+    // codes of the examples for the methods using them.
+    let valid: &[(&str, &str, &[&str])] = &[
+        (
+            "00",
+            "10000000",
+            &["9290701", "539290858", "1501824", "1501832"],
+        ),
+        ("13", "10000000", &["123456600", "1234566"]),
+        ("17", "10000000", &["446786040"]),
+        (
+            "24",
+            "10000000",
+            &["138301", "1306118605", "3307118608", "9307118603"],
+        ),
+        ("29", "10000000", &["9721134869", "3145863029"]),
+        ("31", "10000000", &["1000000524"]),
+        ("35", "10000000", &["108443", "101599"]),
+        ("45", "10000000", &["3545343232", "4013410024"]),
+        ("50", "10000000", &["4000005001", "4444442001"]),
+        (
+            "51",
+            "10000000",
+            &[
+                "1156071",
+                "1156136",
+                "1156078",
+                "1234567",
+                "340968",
+                "201178",
+                "1009588",
+                "156071",
+                "101356073",
+                "199100002",
+                "99100010",
+                "2599100002",
+                "199100004",
+                "2599100003",
+                "3199204090",
+            ],
+        ),
+        ("52", "13051172", &["43001500", "48726458"]),
+        ("53", "16052072", &["382432256"]),
+        ("54", "10000000", &["4964137395", "4900010987"]),
+        ("56", "10000000", &["290545005", "9718304037"]),
+        (
+            "57",
+            "10000000",
+            &[
+                "7500021766",
+                "9400001734",
+                "7800028282",
+                "8100244186",
+                "9322111030",
+            ],
+        ),
+        ("58", "10000000", &["1800881120", "9200654108"]),
+        ("61", "10000000", &["2063099200", "260760481"]),
+        ("63", "10000000", &["123456600", "1234566"]),
+        ("64", "10000000", &["1206473010", "5016511020"]),
+        ("65", "10000000", &["1234567400", "1234567590"]),
+        (
+            "66",
+            "10000000",
+            &["100154508", "101154508", "100154516", "101154516"],
+        ),
+        ("68", "10000000", &["8889654328", "987654324", "987654328"]),
+        ("69", "10000000", &["9721134869", "1234567900"]),
+        (
+            "73",
+            "10000000",
+            &[
+                "3503398", "1340967", "3503391", "1340968", "3503392", "1340966",
+            ],
+        ),
+        (
+            "74",
+            "10000000",
+            &[
+                "1016",
+                "26260",
+                "242243",
+                "242248",
+                "18002113",
+                "1821200043",
+            ],
+        ),
+        ("75", "10000000", &["159863474", "1234567", "300020050"]),
+        ("76", "10000000", &["6543200", "9012345600", "7876543100"]),
+        ("77", "10000000", &["10338", "13844", "65354", "69258"]),
+        (
+            "79",
+            "10000000",
+            &["3230012688", "1550167850", "9011200140"],
+        ),
+        ("80", "10000000", &["340968", "340966"]),
+        ("81", "10000000", &["646440", "1359100", "3199500501"]),
+        (
+            "84",
+            "10000000",
+            &["240699", "350982", "461059", "240692", "350985", "461052"],
+        ),
+        (
+            "85",
+            "10000000",
+            &["1156071", "1156136", "156078", "156071", "99100002"],
+        ),
+        (
+            "86",
+            "10000000",
+            &["340968", "1001171", "1009588", "340960"],
+        ),
+        (
+            "87",
+            "10000000",
+            &[
+                "406",
+                "51768",
+                "10701590",
+                "10720185",
+                "100005",
+                "393814",
+                "950360",
+                "3199500501",
+            ],
+        ),
+        (
+            "88",
+            "10000000",
+            &["2525259", "1000500", "90013000", "92525253", "99913003"],
+        ),
+        ("89", "10000000", &["1098506", "32028008", "218433000"]),
+        (
+            "90",
+            "10000000",
+            &[
+                "1975641", "1988654", "654321", "824491", "996663", "260133", "4923250",
+            ],
+        ),
+        (
+            "91",
+            "10000000",
+            &["2974118000", "2974117000", "8840019000", "8840012000"],
+        ),
+        (
+            "93",
+            "10000000",
+            &["6714790000", "671479", "1277830000", "127783"],
+        ),
+        ("95", "10000000", &["68007003", "847321750", "6450060494"]),
+        (
+            "96",
+            "10000000",
+            &["254100", "9421000009", "208", "101115152", "301204301"],
+        ),
+        (
+            "98",
+            "10000000",
+            &["9619439213", "3009800016", "9619509976", "5989800173"],
+        ),
+        (
+            "A0",
+            "10000000",
+            &["521003287", "54500", "3287", "18761", "28290"],
+        ),
+        ("A1", "10000000", &["10030005", "10030997", "1010030054"]),
+        ("A2", "10000000", &["3456789019", "3456789012"]),
+        ("A3", "10000000", &["1234567897", "9876543210"]),
+        (
+            "A4",
+            "10000000",
+            &[
+                "4711173",
+                "7093330",
+                "4711172",
+                "7093335",
+                "1199503010",
+                "8499421235",
+                "862342",
+                "8997710000",
+                "664040000",
+                "905844",
+                "5030101099",
+                "1123458",
+                "1299503117",
+            ],
+        ),
+        ("A5", "10000000", &["9941510001", "251437"]),
+        ("A6", "10000000", &["800048548", "17"]),
+        (
+            "A8",
+            "10000000",
+            &[
+                "7436661",
+                "7436670",
+                "1359100",
+                "7436660",
+                "7436678",
+                "3503398",
+                "1340967",
+                "199100002",
+            ],
+        ),
+        ("A9", "10000000", &["5043608", "86725", "504360"]),
+        ("B0", "10000000", &["1000000406"]),
+        ("B1", "10000000", &["1434253150", "7414398260"]),
+        ("B2", "10000000", &["20012357", "8000990054"]),
+        ("B3", "10000000", &["1002798417", "9635000101"]),
+        ("B5", "10000000", &["159006955"]),
+        ("B6", "80053782", &["9110000000", "269876545", "487310018"]),
+        ("B7", "10000000", &["700001529"]),
+        ("B8", "10000000", &["734192657", "6932875274", "3145863029"]),
+        (
+            "B9",
+            "10000000",
+            &[
+                "87920187", "41203755", "81069577", "61287958", "58467232", "7125633", "1253657",
+                "4353631",
+            ],
+        ),
+        (
+            "C0",
+            "13051172",
+            &[
+                "43001500",
+                "48726458",
+                "82335729",
+                "734192657",
+                "6932875274",
+            ],
+        ),
+        (
+            "C1",
+            "10000000",
+            &[
+                "446786040",
+                "478046940",
+                "701625830",
+                "701625840",
+                "882095630",
+                "5432112349",
+                "5543223456",
+                "5654334563",
+                "5765445670",
+                "5876556788",
+            ],
+        ),
+        ("C2", "10000000", &["2394871426", "5127485166"]),
+        ("C3", "10000000", &["9294182", "9000420530"]),
+        ("C4", "10000000", &["292932", "9000420530"]),
+        (
+            "C5",
+            "10000000",
+            &[
+                "301168",
+                "302554",
+                "300020050",
+                "300566000",
+                "1000061378",
+                "1000061412",
+                "4450164064",
+                "4863476104",
+                "5000000028",
+                "5000000391",
+                "6450008149",
+                "6800001016",
+                "9000100012",
+                "9000210017",
+                "3060188103",
+                "3070402023",
+            ],
+        ),
+        (
+            "C6",
+            "10000000",
+            &[
+                "65516",
+                "203178249",
+                "1031405209",
+                "1082012201",
+                "2003455189",
+                "2004001016",
+                "3110150986",
+                "3068459207",
+                "5035105948",
+                "5286102149",
+                "4012660028",
+                "4100235626",
+                "6028426119",
+                "6861001755",
+                "7008199027",
+                "7002000023",
+                "8526080015",
+                "8711072264",
+                "9000430223",
+                "9000781153",
+            ],
+        ),
+        ("C7", "10000000", &["3500022", "38150900", "94012341"]),
+        ("C8", "10000000", &["3456789019", "3456789012", "123456789"]),
+        ("C9", "10000000", &["3456789019", "123456789"]),
+        ("D0", "10000000", &["6100272324"]),
+        (
+            "D2",
+            "10000000",
+            &[
+                "189912137",
+                "235308215",
+                "4455667784",
+                "1234567897",
+                "51181008",
+                "71214205",
+            ],
+        ),
+        ("D3", "10000000", &["1600169591", "6019937007"]),
+        (
+            "D4",
+            "10000000",
+            &[
+                "1112048219",
+                "2024601814",
+                "3000005012",
+                "4143406984",
+                "5926485111",
+                "6286304975",
+                "7900256617",
+                "8102228628",
+                "9002364588",
+            ],
+        ),
+        (
+            "D5",
+            "10000000",
+            &[
+                "5999718138",
+                "1799222116",
+                "99632004",
+                "4711173",
+                "7093330",
+                "127787",
+                "4711172",
+                "7093335",
+                "100062",
+                "100088",
+            ],
+        ),
+        ("D6", "10000000", &["3409", "585327", "1650513"]),
+        ("D7", "10000000", &["500018205", "230103715"]),
+        ("D8", "10000000", &["1403414848"]),
+        ("E0", "10000000", &["1234568013", "2610015"]),
+        ("E1", "10000000", &["134211909"]),
+        ("E2", "10000000", &["3831745", "51330335", "1730773432"]),
+    ];
+    for (method, bank_code, accounts) in valid {
+        for account in accounts.iter() {
+            assert_eq!(
+                check_method(method, bank_code, account),
+                bban::NationalCheck::Verified,
+                "method {} account {}",
+                method,
+                account
+            );
+        }
+    }
+
+    let invalid: &[(&str, &str, &[&str])] = &[
+        ("00", "10000000", &["9290702", "539290859"]),
+        ("13", "10000000", &["123456700", "1234567"]),
+        ("17", "10000000", &["446786140"]),
+        ("29", "10000000", &["9721134868"]),
+        ("31", "10000000", &["1000000525"]),
+        ("35", "10000000", &["108444", "101598"]),
+        ("51", "10000000", &["1156072", "199100003"]),
+        ("56", "10000000", &["9718304038"]),
+        ("61", "10000000", &["2063099300", "260760581"]),
+        ("64", "10000000", &["1206474010"]),
+        ("65", "10000000", &["1234567500"]),
+        ("66", "10000000", &["100154509"]),
+        ("68", "10000000", &["8887654328", "987654325"]),
+        ("73", "10000000", &["3503399"]),
+        ("84", "10000000", &["240691"]),
+        ("88", "10000000", &["2525258", "92525254"]),
+        ("91", "10000000", &["2974119000"]),
+        ("98", "10000000", &["9619439214"]),
+        ("A0", "10000000", &["521003288"]),
+        ("B6", "80053782", &["9110000001", "487310019"]),
+        ("B8", "10000000", &["734192658"]),
+        ("C7", "10000000", &["3500023"]),
+        ("D7", "10000000", &["500018206"]),
+        ("E0", "10000000", &["1234568014"]),
+        ("24", "10000000", &["138302"]),
+        ("52", "13051172", &["43001501", "12345678"]),
+        ("53", "16052072", &["382432257"]),
+        ("54", "10000000", &["3964137395"]),
+        ("63", "10000000", &["1123456600"]),
+        ("74", "10000000", &["1011", "26265", "6160000024"]),
+        ("77", "10000000", &["10339"]),
+        ("79", "10000000", &["230012688"]),
+        ("A1", "10000000", &["110030005", "10030998", "30005"]),
+        ("B0", "10000000", &["8137423260", "600000606"]),
+        ("C0", "13051172", &["43001501"]),
+        ("E1", "10000000", &["134211908"]),
+        ("50", "10000000", &["4000004001", "4444443001"]),
+        ("57", "10000000", &["7500021767", "9322111031", "12345678"]),
+        ("75", "10000000", &["159864474", "12345678", "1234568"]),
+        ("76", "10000000", &["1012345600", "9012345700"]),
+        ("85", "10000000", &["1156072", "99100003", "156079"]),
+        ("87", "10000000", &["407", "51769", "10701591"]),
+        ("89", "10000000", &["1098507", "32028009"]),
+        ("93", "10000000", &["6714780000", "127784"]),
+        ("96", "10000000", &["254101", "9421000008"]),
+        ("A4", "10000000", &["4711174"]),
+        ("A8", "10000000", &["7436662", "199100003"]),
+        ("B9", "10000000", &["87920186", "7125634", "123456"]),
+        ("C1", "10000000", &["446786140", "5432112348"]),
+        (
+            "C5",
+            "10000000",
+            &["1000061379", "301169", "2000000000", "12345678"],
+        ),
+        ("C6", "10000000", &["65517", "1031405208"]),
+        ("D1", "10000000", &["3000005012"]),
+        ("D4", "10000000", &["1112048218", "112048219"]),
+        ("D5", "10000000", &["5999718139", "1799222117"]),
+        ("D6", "10000000", &["3408", "585328"]),
+        ("E2", "10000000", &["3831746", "6000000000"]),
+    ];
+    for (method, bank_code, accounts) in invalid {
+        for account in accounts.iter() {
+            assert_ne!(
+                check_method(method, bank_code, account),
+                bban::NationalCheck::Verified,
+                "method {} account {}",
+                method,
+                account
+            );
+        }
+    }
+
+    // Account numbers without a check digit.
+    let unchecked = [
+        ("08", "59999"),
+        ("45", "994681254"),
+        ("45", "1000199999"),
+        ("66", "983393104"),
+        ("68", "400000000"),
+        ("A0", "287"),
+        ("B0", "1197423162"),
+        ("D8", "10000000"),
+        ("57", "185125434"),
+        ("C5", "7000000000"),
+        ("C5", "8500000000"),
+    ];
+    for (method, account) in unchecked.iter() {
+        assert_eq!(
+            check_method(method, "10000000", account),
+            bban::NationalCheck::NotApplicable,
+            "method {} account {}",
+            method,
+            account
+        );
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "serde")]
+
+use schwifty::bban::Component;
+use schwifty::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Payment {
+    iban: Iban,
+    country: Country,
+}
+
+#[test]
+fn serde_iban() {
+    let iban = validate("GB82 WEST 1234 5698 7654 32").unwrap();
+    assert_eq!(
+        serde_json::to_string(&iban).unwrap(),
+        r#""GB82WEST12345698765432""#
+    );
+
+    // Deserializing validates, whitespace is allowed like with `validate`.
+    let payment: Payment =
+        serde_json::from_str(r#"{"iban": "GB82 WEST 1234 5698 7654 32", "country": "GB"}"#)
+            .unwrap();
+    assert_eq!(payment.iban, iban);
+    assert_eq!(payment.country, Country::UnitedKingdom);
+    assert_eq!(
+        serde_json::to_string(&payment).unwrap(),
+        r#"{"iban":"GB82WEST12345698765432","country":"GB"}"#
+    );
+
+    let error =
+        serde_json::from_str::<Payment>(r#"{"iban": "GB82WEST12345698765433", "country": "GB"}"#)
+            .unwrap_err()
+            .to_string();
+    let message = validate("GB82WEST12345698765433").unwrap_err().to_string();
+    assert!(error.starts_with(&message), "{}", error);
+
+    assert!(serde_json::from_str::<Iban>("82").is_err());
+}
+
+#[test]
+fn serde_bic() {
+    let bic: Bic = serde_json::from_str(r#""deutdeff500""#).unwrap();
+    assert_eq!(bic.as_str(), "DEUTDEFF500");
+    assert_eq!(serde_json::to_string(&bic).unwrap(), r#""DEUTDEFF500""#);
+    assert!(serde_json::from_str::<Bic>(r#""DEUTDEFF5""#).is_err());
+}
+
+#[test]
+fn serde_country() {
+    assert_eq!(serde_json::to_string(&Country::Germany).unwrap(), r#""DE""#);
+    assert_eq!(
+        serde_json::from_str::<Country>(r#""AA""#).unwrap(),
+        Country::Internet
+    );
+    assert!(serde_json::from_str::<Country>(r#""Germany""#).is_err());
+}
+
+#[test]
+fn serde_validation_error() {
+    let error = ValidationError::InvalidFormat {
+        country: Country::Belgium,
+        position: 6,
+        component: Component::BankCode,
+    };
+    let json = serde_json::to_string(&error).unwrap();
+    assert_eq!(
+        json,
+        r#"{"InvalidFormat":{"country":"BE","position":6,"component":"BankCode"}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<ValidationError>(&json).unwrap(),
+        error
+    );
+}
+
+#[test]
+fn serde_bban_spec() {
+    let json = serde_json::to_string(&Country::Belgium.bban_spec()).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"country":"BE","segments":["#,
+            r#"{"component":"BankCode","class":"Numeric","offset":0,"length":3},"#,
+            r#"{"component":"AccountNumber","class":"Numeric","offset":3,"length":7},"#,
+            r#"{"component":"NationalCheckDigits","class":"Numeric","offset":10,"length":2}]}"#
+        )
+    );
+}